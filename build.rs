@@ -0,0 +1,62 @@
+//! Generates the list of solutions linked into the main binary by the `registry` feature,
+//! and passes the year of `aoc.toml` to the crate as `AOC_YEAR`, for the checks of `day!`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo::rerun-if-changed=src/bin");
+    println!("cargo::rerun-if-changed=aoc.toml");
+    println!("cargo::rerun-if-env-changed=AOC_YEAR");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+
+    if env::var_os("AOC_YEAR").is_none()
+        && let Some(year) = read_year(&Path::new(&manifest_dir).join("aoc.toml"))
+    {
+        println!("cargo::rustc-env=AOC_YEAR={year}");
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // NOTE: only consider solutions following the `DD.rs` naming of `cargo scaffold`.
+    let mut days: Vec<u8> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let name = entry.file_name().into_string().ok()?;
+                    let day = name.strip_suffix(".rs")?;
+                    (day.len() == 2).then_some(day)?.parse().ok()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort_unstable();
+
+    let mut modules = String::new();
+    let mut solutions = String::new();
+
+    for day in days {
+        let path = bin_dir.join(format!("{day:02}.rs"));
+
+        modules.push_str(&format!(
+            "#[path = {:?}]\n#[allow(dead_code)]\nmod day_{day:02};\n",
+            path.display().to_string()
+        ));
+
+        solutions.push_str(&format!(
+            "    Solution {{ day: day!({day}), run: day_{day:02}::run_solution }},\n"
+        ));
+    }
+
+    let registry = format!("{modules}\npub static SOLUTIONS: &[Solution] = &[\n{solutions}];\n");
+    fs::write(Path::new(&out_dir).join("registry.rs"), registry).unwrap();
+}
+
+/// Reads the year of `aoc.toml`. An invalid file is reported when the crate reads it.
+fn read_year(path: &Path) -> Option<i64> {
+    let config = fs::read_to_string(path).ok()?;
+    let config = config.parse::<toml::Table>().ok()?;
+    config.get("year")?.as_integer()
+}
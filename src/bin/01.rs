@@ -1,4 +1,4 @@
-advent_of_code::solution!(1);
+advent_of_code::solution!(1, parse = parse_input);
 
 fn parse_input(input: &str) -> Vec<i16> {
     input
@@ -16,10 +16,10 @@ fn parse_input(input: &str) -> Vec<i16> {
         .collect()
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let ret = parse_input(input)
-        .into_iter()
-        .scan(50, |lhs, rhs| {
+pub fn part_one(input: &[i16]) -> Option<u64> {
+    let ret = input
+        .iter()
+        .scan(50, |lhs, &rhs| {
             *lhs = (*lhs + rhs).rem_euclid(100);
             Some(*lhs)
         })
@@ -29,10 +29,10 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(ret as _)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let ret = parse_input(input)
-        .into_iter()
-        .scan(50, |lhs @ &mut before, rhs| {
+pub fn part_two(input: &[i16]) -> Option<u64> {
+    let ret = input
+        .iter()
+        .scan(50, |lhs @ &mut before, &rhs| {
             let sum = before + rhs;
             *lhs = sum.rem_euclid(100);
 
@@ -56,13 +56,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(6));
     }
 }
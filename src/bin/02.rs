@@ -1,8 +1,8 @@
 use std::ops::RangeInclusive;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2, parse = parse_input);
 
-fn parse_input(input: &str) -> impl Iterator<Item = RangeInclusive<u64>> {
+fn parse_input(input: &str) -> Vec<RangeInclusive<u64>> {
     input
         .split(',')
         .filter(|line| !line.is_empty())
@@ -10,10 +10,13 @@ fn parse_input(input: &str) -> impl Iterator<Item = RangeInclusive<u64>> {
             let mut items = line.split('-').filter_map(|item| item.parse().ok());
             items.next().zip(items.next()).map(|(a, b)| a..=b)
         })
+        .collect()
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let ret = parse_input(input)
+pub fn part_one(input: &[RangeInclusive<u64>]) -> Option<u64> {
+    let ret = input
+        .iter()
+        .cloned()
         .flatten()
         .filter(|num| {
             let num = num.to_string();
@@ -25,8 +28,10 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(ret)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let ret = parse_input(input)
+pub fn part_two(input: &[RangeInclusive<u64>]) -> Option<u64> {
+    let ret = input
+        .iter()
+        .cloned()
         .flatten()
         .filter(|num| {
             let num = num.to_string().chars().collect::<Vec<_>>();
@@ -53,13 +58,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(1227775554));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(4174379265));
     }
 }
@@ -1,14 +1,15 @@
 use cached::{Cached, UnboundCache, proc_macro::cached};
 use std::sync::LockResult;
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(3, parse = parse_input);
 
-fn parse_input(input: &str) -> impl Iterator<Item = &str> {
-    input.split('\n').filter(|line| !line.is_empty())
+fn parse_input(input: &str) -> Vec<&str> {
+    input.split('\n').filter(|line| !line.is_empty()).collect()
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let ret = parse_input(input)
+pub fn part_one(input: &[&str]) -> Option<u64> {
+    let ret = input
+        .iter()
         .filter_map(|input| {
             let iter = input.chars().filter_map(|c| c.to_digit(10)).map(u64::from);
 
@@ -52,8 +53,9 @@ fn part_two_wrapper(input: &[u64], t: u32, n: usize) -> u64 {
     }
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let ret = parse_input(input)
+pub fn part_two(input: &[&str]) -> Option<u64> {
+    let ret = input
+        .iter()
         .map(|input| {
             let digits = input
                 .chars()
@@ -78,13 +80,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(357));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(3121910778619));
     }
 }
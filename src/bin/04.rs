@@ -1,9 +1,9 @@
 use std::iter::once;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(4, parse = parse_input);
 
-#[derive(Debug)]
-struct Grid {
+#[derive(Clone, Debug)]
+pub struct Grid {
     grid: Vec<Vec<bool>>,
 }
 
@@ -60,9 +60,7 @@ fn parse_input(input: &str) -> Grid {
     Grid::new(grid)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let input = parse_input(input);
-
+pub fn part_one(input: &Grid) -> Option<u64> {
     if let Some((m, n)) = input.len() {
         let ret = (0..m)
             .flat_map(|i| (0..n).map(move |j| (i, j)))
@@ -78,8 +76,8 @@ pub fn part_one(input: &str) -> Option<u64> {
     }
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut input = parse_input(input);
+pub fn part_two(input: &Grid) -> Option<u64> {
+    let mut input = input.clone();
 
     if let Some((m, n)) = input.len() {
         let mut ret = 0;
@@ -115,13 +113,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(43));
     }
 }
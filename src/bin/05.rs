@@ -1,9 +1,9 @@
 use itertools::Itertools;
 use std::{collections::HashSet, hash::RandomState, ops::RangeInclusive};
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse = parse_input);
 
-fn parse_input(input: &str) -> (Vec<RangeInclusive<u64>>, impl Iterator<Item = u64>) {
+fn parse_input(input: &str) -> (Vec<RangeInclusive<u64>>, Vec<u64>) {
     let ranges = input
         .split('\n')
         .take_while(|line| !line.is_empty())
@@ -17,15 +17,17 @@ fn parse_input(input: &str) -> (Vec<RangeInclusive<u64>>, impl Iterator<Item = u
         .split('\n')
         .skip_while(|line| !line.is_empty())
         .filter(|line| !line.is_empty())
-        .filter_map(|line| line.parse().ok());
+        .filter_map(|line| line.parse().ok())
+        .collect();
 
     (ranges, numbers)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (ranges, numbers) = parse_input(input);
+pub fn part_one(input: &(Vec<RangeInclusive<u64>>, Vec<u64>)) -> Option<u64> {
+    let (ranges, numbers) = input;
 
     let ret = numbers
+        .iter()
         .filter(|number| ranges.iter().any(|range| range.contains(number)))
         .count();
 
@@ -50,9 +52,9 @@ fn merge_range(lhs: RangeInclusive<u64>, rhs: RangeInclusive<u64>) -> Option<Ran
     }
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (ranges, _) = parse_input(input);
-    let mut ranges = HashSet::<_, RandomState>::from_iter(ranges);
+pub fn part_two(input: &(Vec<RangeInclusive<u64>>, Vec<u64>)) -> Option<u64> {
+    let (ranges, _) = input;
+    let mut ranges = HashSet::<_, RandomState>::from_iter(ranges.iter().cloned());
 
     let mut previous_len = 0;
     let mut current_len = ranges.len();
@@ -86,13 +88,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(14));
     }
 }
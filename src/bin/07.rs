@@ -3,10 +3,10 @@ use std::{
     iter::once,
 };
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(7, parse = Situation::from);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Cell {
+pub enum Cell {
     Beam,
     Splitter,
     Start,
@@ -27,8 +27,8 @@ impl TryFrom<char> for Cell {
     }
 }
 
-#[derive(Debug)]
-struct Situation {
+#[derive(Clone, Debug)]
+pub struct Situation {
     situation: Vec<Vec<Cell>>,
 }
 
@@ -204,9 +204,8 @@ impl Manifold {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let situation = From::from(input);
-    let mut manifold = Manifold::new(situation);
+pub fn part_one(input: &Situation) -> Option<u64> {
+    let mut manifold = Manifold::new(input.clone());
 
     while manifold.can_run() {
         manifold.run();
@@ -215,9 +214,8 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(manifold.count() as _)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let situation = From::from(input);
-    let mut manifold = Manifold::new(situation);
+pub fn part_two(input: &Situation) -> Option<u64> {
+    let mut manifold = Manifold::new(input.clone());
 
     while manifold.can_run() {
         manifold.run();
@@ -232,13 +230,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&Situation::from(
+            advent_of_code::template::read_file("examples", DAY).as_str(),
+        ));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&Situation::from(
+            advent_of_code::template::read_file("examples", DAY).as_str(),
+        ));
         assert_eq!(result, Some(40));
     }
 }
@@ -1,144 +1,126 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
-use args::{AppArguments, parse};
+use advent_of_code::template::aoc_client::Retry;
+use advent_of_code::template::cli::{Cli, Command};
+use advent_of_code::template::commands::{
+    all, completions, download, leaderboard, read, scaffold, solve, stars, time,
+};
+use advent_of_code::template::registry::Solution;
+use advent_of_code::template::{Year, config, profile};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
-mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+/// Every solution in `src/bin`, linked into this binary to run them in-process.
+#[cfg(feature = "registry")]
+mod registry {
+    use advent_of_code::day;
+    use advent_of_code::template::registry::Solution;
 
-    pub enum AppArguments {
-        Download {
-            day: Day,
-        },
-        Read {
-            day: Day,
-        },
-        Scaffold {
-            day: Day,
-            download: bool,
-            overwrite: bool,
-        },
-        Solve {
-            day: Day,
-            release: bool,
-            dhat: bool,
-            submit: Option<u8>,
-        },
-        All {
-            release: bool,
-        },
-        Time {
-            all: bool,
-            day: Option<Day>,
-            store: bool,
-        },
-        #[cfg(feature = "today")]
-        Today,
-    }
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+#[cfg(feature = "registry")]
+const REGISTRY: Option<&[Solution]> = Some(registry::SOLUTIONS);
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
-            Some("time") => {
-                let all = args.contains("--all");
-                let store = args.contains("--store");
+#[cfg(not(feature = "registry"))]
+const REGISTRY: Option<&[Solution]> = None;
 
-                AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
-                    store,
-                }
-            }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
-            #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
-            }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
-            }
-        };
+/// The registry only links the solutions of the default year, others are run with `cargo run`.
+fn registry() -> Option<&'static [Solution]> {
+    REGISTRY.filter(|_| Year::current().is_none_or(Year::is_default))
+}
 
-        let remaining = args.finish();
-        if !remaining.is_empty() {
-            eprintln!("Warning: unknown argument(s): {remaining:?}.");
-        }
+/// Activates the profile and year of the arguments, which apply to every command.
+fn select_globals(cli: &Cli) {
+    if let Some(name) = &cli.profile
+        && let Err(e) = profile::activate(name)
+    {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
 
-        Ok(app_args)
+    // NOTE: a profile selected with `AOC_PROFILE` is checked as well, before any file is touched.
+    if let Err(e) = profile::active() {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+
+    if let Some(year) = cli.year {
+        year.select();
     }
 }
 
 fn main() {
-    match parse() {
-        Err(err) => {
-            eprintln!("Error: {err}");
-            std::process::exit(1);
+    let cli = Cli::parse_args();
+    select_globals(&cli);
+
+    // NOTE: the defaults of `aoc.toml` only turn flags on.
+    let config = config::get();
+
+    match cli.command {
+        Command::All {
+            days,
+            release,
+            memory,
+            limits,
+        } => all::handle(
+            days,
+            release || config.release,
+            memory || config.memory,
+            limits.jobs(),
+            limits.limits(),
+            registry(),
+        ),
+        Command::Time {
+            days,
+            all,
+            store,
+            compare,
+            threshold,
+            memory,
+            limits,
+        } => time::handle(
+            days,
+            all,
+            store,
+            compare.then(|| threshold.unwrap_or(config.threshold)),
+            memory || config.memory,
+            limits.bench_jobs(),
+            limits.limits(),
+            registry(),
+        ),
+        Command::Download { day } => download::handle(day),
+        Command::Read { day } => read::handle(day),
+        Command::Leaderboard {
+            id,
+            day,
+            json,
+            markdown,
+        } => {
+            let export = match (json, markdown) {
+                (true, _) => Some(leaderboard::Export::Json),
+                (false, true) => Some(leaderboard::Export::Markdown),
+                (false, false) => None,
+            };
+
+            leaderboard::handle(id, day, export);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
-                day,
-                download,
-                overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
-            }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
-        },
-    };
+        Command::Stars { online } => stars::handle(online),
+        Command::Scaffold {
+            day,
+            download,
+            overwrite,
+        } => scaffold::handle(day, overwrite, download.then_some(Retry::Transient)),
+        Command::Solve {
+            day,
+            release,
+            dhat,
+            mut options,
+        } => {
+            options.memory |= config.memory;
+            solve::handle(day, release || config.release, dhat, &options);
+        }
+        #[cfg(feature = "today")]
+        Command::Today { wait } => today::handle(wait),
+        Command::Completions { shell } => completions::handle(shell),
+    }
 }
@@ -0,0 +1,214 @@
+/// Answers returned by solution parts.
+/// Besides numbers and strings, an answer can be a grid that draws block letters, which are read back with OCR.
+use std::fmt::Display;
+
+use crate::template::ocr;
+
+/// Characters that mark a lit cell when parsing a grid from a string.
+const LIT: [char; 2] = ['#', '█'];
+
+/// The answer of a solution part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+    Grid(Grid),
+}
+
+/// A grid of lit and unlit cells, used for answers drawn as block letters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid {
+    rows: Vec<Vec<bool>>,
+}
+
+impl Answer {
+    /// The answer as it is stored and submitted. Grids are decoded, returns [`None`] if that fails.
+    pub fn decode(&self) -> Option<String> {
+        match self {
+            Answer::Unsigned(x) => Some(x.to_string()),
+            Answer::Signed(x) => Some(x.to_string()),
+            Answer::Text(x) => Some(x.clone()),
+            Answer::Grid(grid) => grid.decode(),
+        }
+    }
+
+    /// The drawing of a grid answer.
+    pub fn grid(&self) -> Option<&Grid> {
+        match self {
+            Answer::Grid(grid) => Some(grid),
+            _ => None,
+        }
+    }
+}
+
+impl Grid {
+    /// Creates a grid from rows of cells, `true` being lit.
+    pub fn from_cells<R: IntoIterator<Item = bool>>(rows: impl IntoIterator<Item = R>) -> Self {
+        Grid {
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect(),
+        }
+    }
+
+    pub fn rows(&self) -> &[Vec<bool>] {
+        &self.rows
+    }
+
+    /// Reads the block letters drawn on the grid, returns [`None`] if any of them is unknown.
+    pub fn decode(&self) -> Option<String> {
+        ocr::read(self)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Answer {
+    /// Grids are shown as their letters, or as a drawing if these can't be read.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(x) => write!(f, "{x}"),
+            Answer::Signed(x) => write!(f, "{x}"),
+            Answer::Text(x) => f.write_str(x),
+            Answer::Grid(grid) => match grid.decode() {
+                Some(text) => f.write_str(&text),
+                None => write!(f, "{grid}"),
+            },
+        }
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|&x| if x { '#' } else { '.' }).collect())
+            .collect();
+
+        f.write_str(&lines.join("\n"))
+    }
+}
+
+impl From<&str> for Grid {
+    /// Parses a drawing, `#` and `█` are lit, any other character is not.
+    fn from(value: &str) -> Self {
+        Grid::from_cells(
+            value
+                .lines()
+                .map(|line| line.chars().map(|c| LIT.contains(&c))),
+        )
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident, $target:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(<$target>::from(value))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer!(Unsigned, u128, u8, u16, u32, u64, u128);
+impl_from_integer!(Signed, i128, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        // NOTE: `usize` is at most 64 bits wide on supported platforms.
+        Answer::Unsigned(value as u128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.into())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.into())
+    }
+}
+
+impl From<Grid> for Answer {
+    fn from(value: Grid) -> Self {
+        Answer::Grid(value)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, Grid};
+
+    #[test]
+    fn converts_integers() {
+        assert_eq!(Answer::from(42_u8), Answer::Unsigned(42));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(-42_i64), Answer::Signed(-42));
+        assert_eq!(Answer::from(i128::MIN).to_string(), i128::MIN.to_string());
+        assert_eq!(Answer::from(7_usize).decode(), Some("7".into()));
+    }
+
+    #[test]
+    fn converts_text() {
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".into()));
+        assert_eq!(
+            Answer::from(String::from("1,2,3")).decode(),
+            Some("1,2,3".into())
+        );
+    }
+
+    #[test]
+    fn parses_and_renders_grids() {
+        let grid = Grid::from("#.\n.█");
+        assert_eq!(grid.rows(), &[vec![true, false], vec![false, true]]);
+        assert_eq!(grid.to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn decodes_grid_answers() {
+        let answer = Answer::from(Grid::from(
+            [
+                "#..#.###.",
+                "#..#..#..",
+                "####..#..",
+                "#..#..#..",
+                "#..#..#..",
+                "#..#.###.",
+            ]
+            .join("\n")
+            .as_str(),
+        ));
+
+        assert_eq!(answer.decode(), Some("HI".into()));
+        assert_eq!(answer.to_string(), "HI");
+    }
+
+    #[test]
+    fn renders_unreadable_grids() {
+        let answer = Answer::from(Grid::from("#.#\n.#.\n#.#"));
+        assert_eq!(answer.decode(), None);
+        assert_eq!(answer.to_string(), "#.#\n.#.\n#.#");
+    }
+}
@@ -0,0 +1,115 @@
+/// Store of accepted answers, used to detect regressions in solved days.
+/// Answers live in `data/answers`, one file per day and part, e.g. `01-2.txt`.
+use std::{
+    env, fs,
+    io::Error,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{Day, profile};
+
+/// The verdict of comparing an answer against the accepted one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+/// Returns the path to the file holding the accepted answer of a part.
+#[must_use]
+pub fn get_path(day: Day, part: u8) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join(profile::data_dir("answers"))
+        .join(format!("{day}-{part}.txt"))
+}
+
+/// Reads the accepted answer of a part, if it has been recorded.
+pub fn read(day: Day, part: u8) -> Option<String> {
+    read_from(&get_path(day, part))
+}
+
+/// Records the accepted answer of a part, replacing any previous one.
+pub fn store(day: Day, part: u8, answer: &str) -> Result<PathBuf, Error> {
+    let path = get_path(day, part);
+    store_to(&path, answer)?;
+    Ok(path)
+}
+
+/// Compares an answer, or the lack thereof, against the accepted answer of a part.
+pub fn check(day: Day, part: u8, answer: Option<&str>) -> Check {
+    compare(read(day, part), answer)
+}
+
+fn read_from(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|answer| answer.trim_end_matches('\n').to_string())
+}
+
+fn store_to(path: &Path, answer: &str) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, format!("{answer}\n"))
+}
+
+fn compare(expected: Option<String>, answer: Option<&str>) -> Check {
+    match expected {
+        None => Check::Unknown,
+        Some(expected) if Some(expected.as_str()) == answer => Check::Correct,
+        Some(expected) => Check::Wrong { expected },
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{Check, compare, read_from, store_to};
+
+    #[test]
+    fn handles_unknown_answers() {
+        assert_eq!(compare(None, Some("42")), Check::Unknown);
+        assert_eq!(compare(None, None), Check::Unknown);
+    }
+
+    #[test]
+    fn handles_correct_answers() {
+        assert_eq!(compare(Some("42".into()), Some("42")), Check::Correct);
+    }
+
+    #[test]
+    fn handles_wrong_answers() {
+        assert_eq!(
+            compare(Some("42".into()), Some("41")),
+            Check::Wrong {
+                expected: "42".into()
+            }
+        );
+    }
+
+    #[test]
+    fn handles_missing_answers() {
+        assert_eq!(
+            compare(Some("42".into()), None),
+            Check::Wrong {
+                expected: "42".into()
+            }
+        );
+    }
+
+    #[test]
+    fn roundtrips_stored_answers() {
+        let dir = env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        let path = dir.join("01-1.txt");
+
+        store_to(&path, "#..#\n.##.").unwrap();
+        assert_eq!(read_from(&path), Some("#..#\n.##.".into()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,916 @@
+/// Client of the Advent of Code website, used to download inputs and puzzles and to submit answers.
+/// The session cookie is read from the `AOC_SESSION` env var, or from a file: `AOC_SESSION_FILE`,
+/// `~/.adventofcode.session` or `~/.config/adventofcode.session`, the same places as aoc-cli.
+/// If a profile is active, the cookie is read from the profile instead, see `profile`.
+/// The year is read from `aoc.toml`, and the website can be swapped out with `AOC_URL`, e.g. for testing.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    hash::{BuildHasher, Hasher, RandomState},
+    io::{self, Write, stdout},
+    path::{Path, PathBuf},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::{Day, Year, manifest, markdown, profile};
+
+/// Address of the website, unless overridden with `AOC_URL`.
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the client to the website, as requested by its maintainer.
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long to wait when rate limited without being told for how long.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Added to the cooldowns told by the website, whose seconds are rounded.
+const COOLDOWN_MARGIN: Duration = Duration::from_secs(1);
+
+/// How many times a rate-limited answer is resubmitted before giving up.
+const MAX_RETRIES: usize = 3;
+
+/// How many times a failed download is retried, waiting twice as long each time.
+const DOWNLOAD_RETRIES: u32 = 4;
+
+const DOWNLOAD_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Which failed downloads are retried.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Retry {
+    /// Server errors and failed connections, which may go away.
+    Transient,
+    /// Missing puzzles as well, which the website may not serve for a moment after they unlock.
+    Unlock,
+}
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// No session cookie was found.
+    SessionNotFound,
+    /// The active profile has no session cookie.
+    ProfileSessionNotFound(String),
+    /// `AOC_PROFILE` is not a valid profile name.
+    InvalidProfile(String),
+    /// No year is configured.
+    YearNotSet,
+    /// The puzzle of the day is not unlocked yet.
+    Locked {
+        day: Day,
+        year: u16,
+        remaining: Duration,
+    },
+    /// The website answered with an error status, e.g. `404` for puzzles that are not unlocked yet.
+    BadStatus {
+        status: u16,
+        url: String,
+    },
+    /// The website could not be reached.
+    Transport(String),
+    /// The response of the website could not be understood.
+    UnexpectedResponse(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or save it to \"~/.adventofcode.session\"."
+            ),
+            AocClientError::ProfileSessionNotFound(name) => write!(
+                f,
+                "no session cookie found for profile `{name}`. Save it to \"~/.config/adventofcode/profiles/{name}/session\"."
+            ),
+            AocClientError::InvalidProfile(e) => f.write_str(e),
+            AocClientError::YearNotSet => {
+                write!(
+                    f,
+                    "no year is configured, set `year` in aoc.toml or `AOC_YEAR`."
+                )
+            }
+            AocClientError::Locked {
+                day,
+                year,
+                remaining,
+            } => write!(
+                f,
+                "day {day} of {year} is not unlocked yet, it unlocks in {}.",
+                format_seconds(*remaining)
+            ),
+            AocClientError::BadStatus { status: 400, url } => write!(
+                f,
+                "{url} responded with status 400, the session cookie is likely invalid or expired."
+            ),
+            AocClientError::BadStatus { status: 404, url } => write!(
+                f,
+                "{url} responded with status 404, the puzzle is likely not unlocked yet."
+            ),
+            AocClientError::BadStatus { status, url } => {
+                write!(f, "{url} responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "could not reach the website: {e}"),
+            AocClientError::UnexpectedResponse(e) => write!(f, "unexpected response: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => AocClientError::BadStatus {
+                status,
+                url: response.get_url().to_string(),
+            },
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// The verdict of the website on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently, the answer was not looked at.
+    RateLimited,
+    /// The part was already solved, or the previous part is not yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Whether the website rejected the answer, as opposed to not judging it at all.
+    pub fn is_rejection(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate-limited",
+            Verdict::WrongLevel => "wrong level",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "rate-limited" => Ok(Verdict::RateLimited),
+            "wrong level" => Ok(Verdict::WrongLevel),
+            _ => Err(format!("unknown verdict \"{s}\".")),
+        }
+    }
+}
+
+/// The response of the website to a submitted answer.
+#[derive(Clone, Debug)]
+pub struct Submission {
+    pub verdict: Verdict,
+    /// The message of the website, as text.
+    pub message: String,
+    /// How long the website asks to wait before submitting another answer, if it does.
+    pub cooldown: Option<Duration>,
+}
+
+/// A client bound to a session, year and website.
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        AocClient {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(TIMEOUT)
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    /// Creates a client from the environment, see the module documentation.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = read_session()?;
+        let year = Year::current().ok_or(AocClientError::YearNotSet)?;
+        Ok(AocClient::new(&base_url, &session, year.into_inner()))
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Fails if the puzzle of a day is not unlocked yet, at midnight UTC-5, instead of asking the website.
+    pub fn check_unlocked(&self, day: Day) -> Result<(), AocClientError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let unlock = Duration::from_secs(day.unlock_time(self.year).max(0) as u64);
+
+        match unlock.checked_sub(now) {
+            Some(remaining) if !remaining.is_zero() => Err(AocClientError::Locked {
+                day,
+                year: self.year,
+                remaining,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Fetches the HTML page of the puzzle of a day. Part two is included once part one is solved.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&self.day_url(day))
+    }
+
+    /// Submits the answer to a part of a day.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Submission, AocClientError> {
+        let html = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        parse_submission(&html)
+    }
+
+    /// Submits the answer to a part of a day, resubmitting it when rate limited.
+    /// `wait` is called with every rate-limited submission and the cooldown to wait out,
+    /// the answer is only resubmitted if it returns `true`.
+    pub fn submit_patiently(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+        mut wait: impl FnMut(&Submission, Duration) -> bool,
+    ) -> Result<Submission, AocClientError> {
+        let mut retries = 0;
+
+        loop {
+            let submission = self.submit(day, part, answer)?;
+
+            if submission.verdict != Verdict::RateLimited || retries == MAX_RETRIES {
+                return Ok(submission);
+            }
+
+            let cooldown = submission
+                .cooldown
+                .map_or(DEFAULT_COOLDOWN, |x| x + COOLDOWN_MARGIN);
+
+            if !wait(&submission, cooldown) {
+                return Ok(submission);
+            }
+
+            retries += 1;
+        }
+    }
+
+    /// Fetches the JSON of a private leaderboard. The website asks not to do so more than once every 15 minutes.
+    pub fn leaderboard(&self, id: &str) -> Result<String, AocClientError> {
+        self.get(&format!(
+            "{}/{}/leaderboard/private/view/{id}.json",
+            self.base_url, self.year
+        ))
+    }
+
+    /// Fetches the puzzle of a day and saves it to its puzzle file as Markdown, returns the Markdown.
+    pub fn save_puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let puzzle = puzzle_markdown(&self.puzzle(day)?)?;
+        write_file(&get_puzzle_path(day), &puzzle)?;
+        Ok(puzzle)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .get(url)
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()?)
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Prints the puzzle of a day and saves it to its puzzle file.
+pub fn read(day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    client.check_unlocked(day)?;
+    println!("Fetching puzzle for day {}, {}...\n", day, client.year());
+
+    let puzzle = client.save_puzzle(day)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+/// Saves the input and the puzzle of a day to their files, returns the HTML of the puzzle page.
+/// Warns if the input differs from the one on disk or the one downloaded before, see [`manifest`].
+pub fn download(day: Day, retry: Retry) -> Result<String, AocClientError> {
+    let client = AocClient::from_env()?;
+    client.check_unlocked(day)?;
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+
+    let input = with_retries(retry, || client.input(day))?;
+    let mut manifest = manifest::read().unwrap_or_else(|e| {
+        eprintln!("Warning: could not read the input manifest, starting a new one: {e}");
+        manifest::Manifest::default()
+    });
+
+    let existing = fs::read_to_string(&input_path).ok();
+    let warnings = manifest::check(existing.as_deref(), manifest.get(day), &input);
+    for warning in &warnings {
+        eprintln!("Warning: \"{}\" {warning}.", input_path.display());
+    }
+
+    // NOTE: an input that differs from the download is never clobbered, it may hold edits.
+    if warnings.contains(&manifest::Warning::Overwritten) {
+        let backup_path = manifest::get_backup_path(&input_path);
+        fs::copy(&input_path, &backup_path)?;
+        eprintln!(
+            "Warning: kept the previous input in \"{}\".",
+            backup_path.display()
+        );
+    }
+
+    write_file(&input_path, &input)?;
+    manifest.insert(day, manifest::hash(&input));
+    if let Err(e) = manifest::write(&manifest) {
+        eprintln!("Warning: could not update the input manifest: {e}");
+    }
+
+    let html = with_retries(retry, || client.puzzle(day))?;
+    write_file(&puzzle_path, &puzzle_markdown(&html)?)?;
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(html)
+}
+
+/// Retries a download that failed for a reason that may go away, e.g. a puzzle that unlocked moments ago.
+/// The delays are jittered so that clients started at the same time don't retry at the same time.
+fn with_retries<T>(
+    retry: Retry,
+    mut fetch: impl FnMut() -> Result<T, AocClientError>,
+) -> Result<T, AocClientError> {
+    let mut attempt = 0;
+
+    loop {
+        match fetch() {
+            Err(e) if attempt < DOWNLOAD_RETRIES && is_transient(&e, retry) => {
+                let delay = retry_delay(attempt) + jitter(Duration::from_secs(1));
+                eprintln!("{e} Retrying in {}...", format_seconds(delay));
+                thread::sleep(delay);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Returns the delay before a retry, without jitter: 2s, 4s, 8s, then 16s.
+fn retry_delay(attempt: u32) -> Duration {
+    DOWNLOAD_RETRY_DELAY * 2u32.pow(attempt)
+}
+
+fn is_transient(e: &AocClientError, retry: Retry) -> bool {
+    match e {
+        // NOTE: unlocks are checked before downloading, so a missing puzzle is only expected right at one.
+        AocClientError::BadStatus { status: 404, .. } => retry == Retry::Unlock,
+        AocClientError::BadStatus { status, .. } => *status >= 500,
+        AocClientError::Transport(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns a random duration up to `max`.
+pub fn jitter(max: Duration) -> Duration {
+    let random = RandomState::new().build_hasher().finish();
+    max.mul_f64(random as f64 / u64::MAX as f64)
+}
+
+/// Waits for a duration while counting it down on the terminal.
+pub fn countdown(duration: Duration, label: &str) {
+    let deadline = Instant::now() + duration;
+
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        print!("\r{label} {} ", format_seconds(remaining));
+        let _ = stdout().flush();
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    // NOTE: clear the countdown once it is over.
+    print!("\r\x1b[2K");
+    let _ = stdout().flush();
+}
+
+/// Formats a duration as e.g. `1m 05s`, `2h 01m 05s` or `3d 02h 01m`.
+fn format_seconds(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64().ceil() as u64;
+
+    match seconds {
+        0..60 => format!("{seconds}s"),
+        60..3600 => format!("{}m {:02}s", seconds / 60, seconds % 60),
+        3600..86400 => format!(
+            "{}h {:02}m {:02}s",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        ),
+        _ => format!(
+            "{}d {:02}h {:02}m",
+            seconds / 86400,
+            seconds / 3600 % 24,
+            seconds / 60 % 60
+        ),
+    }
+}
+
+fn get_input_path(day: Day) -> PathBuf {
+    profile::data_dir("inputs").join(format!("{day}.txt"))
+}
+
+fn get_puzzle_path(day: Day) -> PathBuf {
+    profile::data_dir("puzzles").join(format!("{day}.md"))
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), io::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, contents)
+}
+
+fn read_session() -> Result<String, AocClientError> {
+    // NOTE: the session of a profile takes precedence, as it is selected explicitly.
+    if let Some(name) = profile::active().map_err(AocClientError::InvalidProfile)? {
+        return profile::get_session_path(&name)
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+            .ok_or(AocClientError::ProfileSessionNotFound(name));
+    }
+
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session);
+    }
+
+    let mut candidates: Vec<PathBuf> = env::var_os("AOC_SESSION_FILE")
+        .map(PathBuf::from)
+        .into_iter()
+        .collect();
+
+    if let Some(home) = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")) {
+        let home = PathBuf::from(home);
+        candidates.push(home.join(".adventofcode.session"));
+        candidates.push(home.join(".config").join("adventofcode.session"));
+    }
+
+    candidates
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or(AocClientError::SessionNotFound)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reads the verdict from the page the website responds with to a submission.
+fn parse_submission(html: &str) -> Result<Submission, AocClientError> {
+    let message = articles(html)
+        .first()
+        .map(|article| html_to_text(article))
+        .ok_or_else(|| AocClientError::UnexpectedResponse("no message in response.".into()))?;
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if message.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if message.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return Err(AocClientError::UnexpectedResponse(message));
+    };
+
+    let cooldown = parse_cooldown(&message);
+
+    Ok(Submission {
+        verdict,
+        message,
+        cooldown,
+    })
+}
+
+/// Reads how long to wait before the next answer from a message. Rate limits read e.g.
+/// "You have 1m 5s left to wait.", repeated wrong answers e.g. "please wait 5 minutes before trying again.".
+fn parse_cooldown(message: &str) -> Option<Duration> {
+    if let Some(wait) = between(
+        &message.to_lowercase(),
+        "please wait ",
+        " before trying again",
+    ) {
+        return match wait.split_once(' ') {
+            Some(("one", "minute")) => Some(Duration::from_secs(60)),
+            Some((value, "minutes")) => {
+                value.parse().ok().map(|x: u64| Duration::from_secs(x * 60))
+            }
+            _ => None,
+        };
+    }
+
+    between(message, "You have ", " left to wait")?
+        .split_whitespace()
+        .map(|x| {
+            let (value, unit) = x.split_at(x.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+fn between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = s.find(start)? + start.len();
+    let to = s[from..].find(end)? + from;
+    Some(&s[from..to])
+}
+
+/// Turns the puzzle page into Markdown, the descriptions of both parts separated by a blank line.
+fn puzzle_markdown(html: &str) -> Result<String, AocClientError> {
+    let articles = articles(html);
+
+    if articles.is_empty() {
+        return Err(AocClientError::UnexpectedResponse(
+            "no puzzle description in page.".into(),
+        ));
+    }
+
+    Ok(articles
+        .iter()
+        .map(|article| markdown::from_html(article))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        + "\n")
+}
+
+/// Returns the contents of every `<article>` element of a page.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+
+        let content_start = start + open_end + 1;
+
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end..];
+    }
+
+    articles
+}
+
+/// Strips the tags of an HTML fragment and decodes its entities, as used for the messages of the website.
+fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+
+        let Some(end) = rest[start..].find('>') else {
+            rest = "";
+            break;
+        };
+
+        // NOTE: keep blocks apart.
+        let tag = &rest[start + 1..start + end];
+        if ["/p", "/h2", "/pre", "/li"].contains(&tag) {
+            text.push('\n');
+        }
+
+        rest = &rest[start + end + 1..];
+    }
+
+    text.push_str(rest);
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+        time::Duration,
+    };
+
+    use super::{
+        AocClient, AocClientError, Retry, Verdict, is_transient, parse_submission, puzzle_markdown,
+        retry_delay,
+    };
+    use crate::day;
+
+    /// Serves `responses` in order on a local port, returns its URL and the requests it received.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(x) = line.to_lowercase().strip_prefix("content-length: ") {
+                        content_length = x.trim().parse().unwrap();
+                    }
+                    if line == "\r\n" {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+
+                let mut payload = vec![0; content_length];
+                reader.read_exact(&mut payload).unwrap();
+                request.push_str(&String::from_utf8(payload).unwrap());
+                requests.push(request);
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let (url, server) = serve(vec![(200, "1\n2\n3\n")]);
+        let client = AocClient::new(&url, "abc\n", 2025);
+
+        assert_eq!(client.input(day!(1)).unwrap(), "1\n2\n3\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2025/day/1/input "));
+        assert!(requests[0].contains("session=abc\r\n"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, server) = serve(vec![(
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        )]);
+        let client = AocClient::new(&url, "abc", 2025);
+
+        let submission = client.submit(day!(7), 2, "42").unwrap();
+        assert_eq!(submission.verdict, Verdict::Correct);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2025/day/7/answer "));
+        assert!(requests[0].ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (url, server) = serve(vec![(404, "Not Found")]);
+        let client = AocClient::new(&url, "abc", 2025);
+
+        assert!(matches!(
+            client.puzzle(day!(12)),
+            Err(AocClientError::BadStatus { status: 404, .. })
+        ));
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let verdict = |message: &str| {
+            parse_submission(&format!("<article><p>{message}</p></article>"))
+                .unwrap()
+                .verdict
+        };
+
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(verdict("That's not the right answer."), Verdict::Wrong);
+        assert_eq!(
+            verdict("You gave an answer too recently. You have 1m 5s left to wait."),
+            Verdict::RateLimited
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level. Did you already complete it?"),
+            Verdict::WrongLevel
+        );
+    }
+
+    #[test]
+    fn parses_cooldowns() {
+        let cooldown = |message: &str| {
+            parse_submission(&format!("<article><p>{message}</p></article>"))
+                .unwrap()
+                .cooldown
+        };
+
+        assert_eq!(
+            cooldown("You gave an answer too recently. You have 1m 5s left to wait."),
+            Some(Duration::from_secs(65))
+        );
+        assert_eq!(
+            cooldown("That's not the right answer. Please wait one minute before trying again."),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            cooldown(
+                "That's not the right answer; your answer is too low. Because you have guessed incorrectly 6 times on this puzzle, please wait 5 minutes before trying again."
+            ),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(cooldown("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn resubmits_when_rate_limited() {
+        let (url, server) = serve(vec![
+            (
+                200,
+                "<article><p>You gave an answer too recently. You have 2s left to wait.</p></article>",
+            ),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let client = AocClient::new(&url, "abc", 2025);
+        let mut waits = vec![];
+
+        let submission = client
+            .submit_patiently(day!(1), 1, "42", |submission, cooldown| {
+                waits.push((submission.verdict, cooldown));
+                true
+            })
+            .unwrap();
+
+        assert_eq!(submission.verdict, Verdict::Correct);
+        assert_eq!(waits, vec![(Verdict::RateLimited, Duration::from_secs(3))]);
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn stops_resubmitting_when_told() {
+        let (url, server) = serve(vec![(
+            200,
+            "<article><p>You gave an answer too recently.</p></article>",
+        )]);
+        let client = AocClient::new(&url, "abc", 2025);
+
+        let submission = client
+            .submit_patiently(day!(1), 1, "42", |_, cooldown| {
+                assert_eq!(cooldown, Duration::from_secs(60));
+                false
+            })
+            .unwrap();
+
+        assert_eq!(submission.verdict, Verdict::RateLimited);
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn extracts_puzzle_markdown() {
+        let html = "<main><article class=\"day-desc\"><h2>--- Day 1 ---</h2><p>Find &lt;x&gt;.</p></article>\
+            <p>Your answer was 42.</p><article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Again.</p></article></main>";
+
+        assert_eq!(
+            puzzle_markdown(html).unwrap(),
+            "## --- Day 1 ---\n\nFind <x>.\n\n## --- Part Two ---\n\nAgain.\n"
+        );
+        assert!(puzzle_markdown("<main></main>").is_err());
+    }
+
+    #[test]
+    fn retries_missing_puzzles_only_at_unlock() {
+        let status = |status| AocClientError::BadStatus {
+            status,
+            url: "https://adventofcode.com/2025/day/1".into(),
+        };
+
+        assert!(!is_transient(&status(404), Retry::Transient));
+        assert!(is_transient(&status(404), Retry::Unlock));
+        assert!(is_transient(&status(502), Retry::Transient));
+        assert!(!is_transient(&status(400), Retry::Unlock));
+        assert!(is_transient(
+            &AocClientError::Transport("timed out".into()),
+            Retry::Transient
+        ));
+        assert!(!is_transient(&AocClientError::YearNotSet, Retry::Unlock));
+    }
+
+    #[test]
+    fn backs_off_exponentially() {
+        let delays: Vec<_> = (0..4)
+            .map(|attempt| retry_delay(attempt).as_secs())
+            .collect();
+        assert_eq!(delays, [2, 4, 8, 16]);
+    }
+}
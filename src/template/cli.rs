@@ -0,0 +1,289 @@
+/// The command-line interface of the main binary, declared once for parsing, `--help` and shell completions.
+/// The flags of the solution binaries are declared by [`runner::Options`], which `solve` passes on.
+use std::env;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+use clap_complete::Shell;
+
+use crate::template::{Day, DaySet, Limits, Year, config, runner};
+
+#[derive(Parser)]
+#[command(
+    name = "advent_of_code",
+    about = "Scaffold, run and benchmark Advent of Code solutions.",
+    after_help = "The commands are usually run through their cargo aliases, e.g. `cargo solve 1`."
+)]
+pub struct Cli {
+    /// Use the session and data of a profile in `~/.config/adventofcode/profiles`.
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Solve the puzzles of another year than the one of `aoc.toml`.
+    #[arg(long, global = true)]
+    pub year: Option<Year>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Create the solution, input and example files of a day.
+    Scaffold {
+        /// Day of the puzzle, e.g. `1`.
+        day: Day,
+        /// Download the input and puzzle, and scaffold tests from its examples.
+        #[arg(long)]
+        download: bool,
+        /// Replace an existing solution file.
+        #[arg(long)]
+        overwrite: bool,
+    },
+    /// Download the input and puzzle of a day.
+    Download {
+        /// Day of the puzzle, e.g. `1`.
+        day: Day,
+    },
+    /// Print the puzzle of a day.
+    Read {
+        /// Day of the puzzle, e.g. `1`.
+        day: Day,
+    },
+    /// Run the solution of a day.
+    Solve {
+        /// Day of the puzzle, e.g. `1`.
+        day: Day,
+        /// Run an optimized build.
+        #[arg(long)]
+        release: bool,
+        /// Profile the heap with dhat.
+        #[arg(long)]
+        dhat: bool,
+        #[command(flatten)]
+        options: runner::Options,
+    },
+    /// Run the solutions of several days.
+    All {
+        /// Days to run, e.g. `1..5,7,10..`, `odd`, `unsolved` or `slow`. Defaults to every day.
+        days: Option<DaySet>,
+        /// Run an optimized build.
+        #[arg(long)]
+        release: bool,
+        /// Measure the heap usage of every step.
+        #[arg(long)]
+        memory: bool,
+        #[command(flatten)]
+        limits: LimitArgs,
+    },
+    /// Benchmark the solutions of several days.
+    Time {
+        /// Days to bench, e.g. `1..5,7,10..`, `odd`, `unsolved` or `slow`.
+        /// Defaults to the days without stored timings, or with `--compare` to those with them.
+        days: Option<DaySet>,
+        /// Bench every day, including those with stored timings.
+        #[arg(long)]
+        all: bool,
+        /// Store the timings and update the readme.
+        #[arg(long)]
+        store: bool,
+        /// Compare the timings with the stored ones, failing on regressions.
+        #[arg(long)]
+        compare: bool,
+        /// Slowdown in percent above which `--compare` reports a regression [default: `bench.threshold` of `aoc.toml`]
+        #[arg(long, value_name = "PERCENT", requires = "compare")]
+        threshold: Option<f64>,
+        /// Measure the heap usage of every step.
+        #[arg(long)]
+        memory: bool,
+        #[command(flatten)]
+        limits: LimitArgs,
+    },
+    /// Show a private leaderboard.
+    Leaderboard {
+        /// Id of the leaderboard, defaults to `AOC_LEADERBOARD_ID`.
+        id: Option<String>,
+        /// Show the results of a single day. Exports always hold every day.
+        #[arg(long, conflicts_with_all = ["json", "markdown"])]
+        day: Option<Day>,
+        /// Export the leaderboard as JSON.
+        #[arg(long, conflicts_with = "markdown")]
+        json: bool,
+        /// Export the leaderboard as a markdown table.
+        #[arg(long)]
+        markdown: bool,
+    },
+    /// Update the stars table in the readme.
+    Stars {
+        /// Include the stars of your account on adventofcode.com.
+        #[arg(long)]
+        online: bool,
+    },
+    /// Scaffold, download and read the puzzle of today.
+    #[cfg(feature = "today")]
+    Today {
+        /// Wait for the next puzzle to unlock.
+        #[arg(long)]
+        wait: bool,
+    },
+    /// Print a completion script for a shell.
+    Completions {
+        /// Shell to complete the commands in.
+        shell: Shell,
+    },
+}
+
+/// Limits of solution runs, shared by `all` and `time`.
+#[derive(Args)]
+pub struct LimitArgs {
+    /// Number of days to run concurrently [default: `defaults.jobs` of `aoc.toml` for `all`, 1 for `time`]
+    #[arg(long, value_name = "N")]
+    pub jobs: Option<usize>,
+    /// Kill a part whose first run takes longer than this [default: `limits.timeout` of `aoc.toml`]
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+    /// Cap on the address space of every solution in MiB [default: `limits.memory_limit` of `aoc.toml`]
+    #[arg(long, value_name = "MiB")]
+    pub memory_limit: Option<u64>,
+}
+
+impl LimitArgs {
+    /// Returns the number of days `all` runs concurrently, from the flag or else from `aoc.toml`.
+    #[must_use]
+    pub fn jobs(&self) -> usize {
+        self.jobs.unwrap_or(config::get().jobs)
+    }
+
+    /// Returns the number of days `time` benches concurrently. Benchmarks stay sequential unless
+    /// `--jobs` is passed, so that they don't compete for the CPU.
+    #[must_use]
+    pub fn bench_jobs(&self) -> usize {
+        self.jobs.unwrap_or(1)
+    }
+
+    /// Returns the limits of the flags, or else of `aoc.toml`.
+    #[must_use]
+    pub fn limits(&self) -> Limits {
+        let config = config::get();
+
+        Limits {
+            timeout: self.timeout.or(config.timeout),
+            memory_limit: self.memory_limit.or(config.memory_limit),
+        }
+    }
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    s.parse()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| "expecting a positive number of seconds".into())
+}
+
+impl Cli {
+    /// Parses the command-line arguments, exits with the help or an error if they are invalid.
+    #[must_use]
+    pub fn parse_args() -> Self {
+        // NOTE: the year decides which days are valid, so it is selected before the days are parsed.
+        if let Some(year) = find_year(env::args()) {
+            year.select();
+        }
+
+        Self::parse()
+    }
+}
+
+/// Finds a valid `--year` in the arguments. Invalid ones are reported when the arguments are parsed.
+fn find_year(args: impl IntoIterator<Item = String>) -> Option<Year> {
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let value = match arg.strip_prefix("--year") {
+            Some("") => args.next(),
+            Some(value) => value.strip_prefix('=').map(String::from),
+            None => continue,
+        };
+
+        return value?.parse().ok();
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Cli, find_year};
+    use crate::template::runner::Options;
+    use clap::{CommandFactory, Parser, error::ErrorKind};
+
+    fn parse(args: &str) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from(std::iter::once("advent_of_code").chain(args.split_whitespace()))
+    }
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn declares_a_valid_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        let kind = |args| parse(args).err().map(|e| e.kind());
+
+        assert!(parse("solve 1 --release --time --submit 2").is_ok());
+        assert!(parse("time 1..5 --compare --threshold 5 --jobs 2").is_ok());
+        assert_eq!(kind("solve 1 --tiem"), Some(ErrorKind::UnknownArgument));
+        assert_eq!(kind("slove 1"), Some(ErrorKind::InvalidSubcommand));
+        assert_eq!(kind("solve 1 --submit 3"), Some(ErrorKind::ValueValidation));
+        assert_eq!(
+            kind("time --threshold 5"),
+            Some(ErrorKind::MissingRequiredArgument)
+        );
+        assert_eq!(kind("all --timeout=-1"), Some(ErrorKind::ValueValidation));
+        assert_eq!(
+            kind("leaderboard --json --markdown"),
+            Some(ErrorKind::ArgumentConflict)
+        );
+        assert_eq!(
+            kind("leaderboard --day 1 --json"),
+            Some(ErrorKind::ArgumentConflict)
+        );
+    }
+
+    #[test]
+    fn finds_years() {
+        assert_eq!(find_year(args("solve 1 --year 2024")), "2024".parse().ok());
+        assert_eq!(find_year(args("--year=2016 all")), "2016".parse().ok());
+        assert_eq!(find_year(args("solve 1 --year 20x4")), None);
+        assert_eq!(find_year(args("solve 1 --year")), None);
+        assert_eq!(find_year(args("solve 1")), None);
+    }
+
+    #[test]
+    fn passes_on_solution_options() {
+        let options = Options {
+            time: true,
+            memory_limit: Some(512),
+            submit: Some(1),
+            force: true,
+            ..Options::default()
+        };
+
+        assert_eq!(
+            options.to_args(),
+            [
+                "--time",
+                "--force",
+                "--memory-limit",
+                "512",
+                "--submit",
+                "1"
+            ]
+        );
+    }
+}
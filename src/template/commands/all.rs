@@ -1,5 +1,22 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::registry::Solution;
+use crate::template::run_multi::{Limits, run_multi};
+use crate::template::{DaySet, all_days};
+
+pub fn handle(
+    days: Option<DaySet>,
+    is_release: bool,
+    is_memory: bool,
+    jobs: usize,
+    limits: Limits,
+    registry: Option<&[Solution]>,
+) {
+    let days = days.map_or_else(|| all_days().collect(), |days| days.days());
+
+    let (_, summary) = run_multi(&days, is_release, false, is_memory, jobs, limits, registry);
+
+    if !summary.is_success() {
+        process::exit(1);
+    }
 }
@@ -1,16 +1,10 @@
-use crate::template::{Day, aoc_cli};
+use crate::template::Day;
+use crate::template::aoc_client::{self, Retry};
 use std::process;
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
-
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = aoc_client::download(day, Retry::Transient) {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
     };
 }
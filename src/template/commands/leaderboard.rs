@@ -0,0 +1,68 @@
+use std::{env, process};
+
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::AocClient;
+use crate::template::leaderboard::{self, format_day, format_rankings, to_markdown};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+
+/// Formats a leaderboard can be exported as instead of being rendered for the terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Export {
+    Json,
+    Markdown,
+}
+
+pub fn handle(id: Option<String>, day: Option<Day>, export: Option<Export>) {
+    let Some(id) = id.or_else(|| env::var("AOC_LEADERBOARD_ID").ok()) else {
+        eprintln!("No leaderboard specified. Pass its ID, or set `AOC_LEADERBOARD_ID`.");
+        process::exit(1);
+    };
+
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to set up the Advent of Code client: {e}");
+            process::exit(1);
+        }
+    };
+
+    let (leaderboard, cache_age) = match leaderboard::load(&client, &id) {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    match export {
+        Some(Export::Json) => match JsonValue::from(&leaderboard).format() {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("failed to export leaderboard: {e}");
+                process::exit(1);
+            }
+        },
+        Some(Export::Markdown) => println!("{}", to_markdown(&leaderboard)),
+        None => {
+            let cached = cache_age.map_or_else(String::new, |age| {
+                format!(
+                    " {ANSI_ITALIC}(cached {}m ago){ANSI_RESET}",
+                    age.as_secs() / 60
+                )
+            });
+
+            println!(
+                "{ANSI_BOLD}Leaderboard {id} · {}{ANSI_RESET}{cached}",
+                leaderboard.year
+            );
+            println!();
+            println!("{}", format_rankings(&leaderboard));
+
+            if let Some(day) = day.or_else(|| leaderboard.last_day()) {
+                println!();
+                println!("{}", format_day(&leaderboard, day));
+            }
+        }
+    }
+}
@@ -1,6 +1,11 @@
 pub mod all;
+pub mod completions;
 pub mod download;
+pub mod leaderboard;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
@@ -1,10 +1,14 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::aoc_client::{self, Retry};
+use crate::template::examples::{self, Example};
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::{Day, Year, profile};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
@@ -19,7 +23,12 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
+fn create_file(path: impl AsRef<Path>) -> Result<File, std::io::Error> {
+    // NOTE: the data folders of another year than the default one may not exist yet.
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,11 +36,33 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// How a scaffolded test reads its example, given the index of the example file.
+/// Solutions of another year than the default one read the examples of their `PUZZLE`.
+fn example_reader(index: usize, year: Option<Year>) -> String {
+    match (index, year) {
+        (0, None) => "read_file(\"examples\", DAY)".into(),
+        (_, None) => format!("read_file_part(\"examples\", DAY, {})", index + 1),
+        (0, Some(_)) => "read_year_file(\"examples\", PUZZLE)".into(),
+        (_, Some(_)) => format!("read_year_file_part(\"examples\", PUZZLE, {})", index + 1),
+    }
+}
+
+/// The expected answer of a scaffolded test. Only numbers fit the `u64` returned by the template.
+fn expected_answer(example: Option<&Example>) -> String {
+    example
+        .and_then(|x| x.answer.as_ref())
+        .and_then(|x| x.parse::<u64>().ok())
+        .map_or_else(|| "None".into(), |x| format!("Some({x})"))
+}
+
+/// Scaffolds a day, downloading its input and puzzle first if `download` says how to retry.
+pub fn handle(day: Day, overwrite: bool, download: Option<Retry>) {
+    let input_path = profile::data_dir("inputs").join(format!("{day}.txt"));
+    let module_path = get_path_for_bin(day);
+    let year = Year::current().filter(|year| !year.is_default());
 
+    // NOTE: the module file is created first, so that nothing is downloaded or overwritten
+    // if it already exists and `--overwrite` is not passed.
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
         Err(e) => {
@@ -40,11 +71,40 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    // NOTE: the puzzle is downloaded before the module is written, so that its examples can be scaffolded.
+    let (downloaded, examples) = if let Some(retry) = download {
+        match aoc_client::download(day, retry) {
+            Ok(html) => (true, examples::extract(&html)),
+            Err(e) => {
+                eprintln!("failed to download puzzle: {e}");
+                (false, vec![])
+            }
+        }
+    } else {
+        (false, vec![])
+    };
+
+    let (example_inputs, example_indices) = examples::distinct_inputs(&examples, 2);
+
+    let day_number = match year {
+        Some(year) => format!("year = {year}, {}", day.into_inner()),
+        None => day.into_inner().to_string(),
+    };
+    let mut module = MODULE_TEMPLATE.replace("%DAY_NUMBER%", &day_number);
+
+    for (part, index) in example_indices.iter().enumerate() {
+        module = module
+            .replace(
+                &format!("%PART_{}_EXAMPLE%", part + 1),
+                &example_reader(*index, year),
+            )
+            .replace(
+                &format!("%PART_{}_ANSWER%", part + 1),
+                &expected_answer(examples.get(part)),
+            );
+    }
+
+    match file.write_all(module.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -54,26 +114,58 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    if !downloaded {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", input_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    let examples_dir = profile::data_dir("examples");
+
+    if example_inputs.is_empty() {
+        let example_path = examples_dir.join(format!("{day}.txt"));
+
+        match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", example_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    }
+
+    for (index, input) in example_inputs.iter().enumerate() {
+        let example_path = match index {
+            0 => examples_dir.join(format!("{day}.txt")),
+            _ => examples_dir.join(format!("{day}-{}.txt", index + 1)),
+        };
+
+        match create_file(&example_path).and_then(|mut file| file.write_all(input.as_bytes())) {
+            Ok(()) => {
+                println!("Created example file \"{}\"", example_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    match year {
+        Some(year) => println!("🎄 Type `cargo solve {day} --year {year}` to run your solution."),
+        None => println!("🎄 Type `cargo solve {day}` to run your solution."),
+    }
+
+    if download.is_some() && !downloaded {
+        process::exit(1);
+    }
 }
@@ -1,9 +1,10 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::runner::Options;
+use crate::template::{Day, get_bin_name};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(day: Day, release: bool, dhat: bool, options: &Options) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), get_bin_name(day)];
 
     if dhat {
         cmd_args.extend([
@@ -17,11 +18,7 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     }
 
     cmd_args.push("--".to_string());
-
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
-    }
+    cmd_args.extend(options.to_args());
 
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
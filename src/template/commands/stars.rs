@@ -0,0 +1,67 @@
+use std::{env, process};
+
+use crate::template::aoc_client::AocClient;
+use crate::template::leaderboard;
+use crate::template::readme_stars::{self, Progress};
+use crate::template::{Year, config};
+
+pub fn handle(online: bool) {
+    let Some(year) = Year::current() else {
+        eprintln!("No year is configured, set `year` in aoc.toml or `AOC_YEAR`.");
+        process::exit(1);
+    };
+
+    let mut progress = readme_stars::local(year);
+
+    if online {
+        progress.merge(&fetch_progress());
+    }
+
+    let readme = config::get().readme.display();
+
+    match readme_stars::update(year, &progress) {
+        Ok(()) => println!(
+            "Updated the {year} stars in {readme} ({} ⭐).",
+            progress.stars()
+        ),
+        Err(e) => {
+            eprintln!("failed to update the stars in {readme}: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads the stars of the user from their own private leaderboard, whose ID is their user ID.
+fn fetch_progress() -> Progress {
+    let Some(user_id) = env::var("AOC_USER_ID").ok().map(|x| x.trim().to_string()) else {
+        eprintln!("`--online` needs your user ID in `AOC_USER_ID`.");
+        process::exit(1);
+    };
+
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to set up the Advent of Code client: {e}");
+            process::exit(1);
+        }
+    };
+
+    let (leaderboard, _) = match leaderboard::load(&client, &user_id) {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    let Some(member) = leaderboard
+        .members
+        .iter()
+        .find(|member| member.id.to_string() == user_id)
+    else {
+        eprintln!("User {user_id} is not a member of their own leaderboard, check `AOC_USER_ID`.");
+        process::exit(1);
+    };
+
+    Progress::from(member)
+}
@@ -1,16 +1,32 @@
-use std::collections::HashSet;
+use std::process;
 
-use crate::template::run_multi::run_multi;
+use crate::template::registry::Solution;
+use crate::template::run_multi::{Limits, run_multi};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{ANSI_BOLD, ANSI_RESET, DaySet, all_days, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    days: Option<DaySet>,
+    run_all: bool,
+    store: bool,
+    compare: Option<f64>,
+    is_memory: bool,
+    jobs: usize,
+    limits: Limits,
+    registry: Option<&[Solution]>,
+) {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
+    let days_to_run = days.map_or_else(
         || {
             if run_all {
                 all_days().collect()
+            } else if compare.is_some() {
+                // NOTE: only days with stored timings can be compared.
+                all_days()
+                    .filter(|day| stored_timings.data.iter().any(|t| t.day == *day))
+                    .collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
                 all_days()
@@ -18,10 +34,23 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |days| days.days(),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let (timings, summary) = run_multi(&days_to_run, true, true, is_memory, jobs, limits, registry);
+    let timings = timings.unwrap();
+
+    // `compare` holds the threshold above which a slowdown is a regression.
+    if let Some(threshold) = compare
+        && let Err(e) = compare_timings(&stored_timings, &timings, threshold)
+    {
+        if store {
+            eprintln!("{e}, not storing them.");
+        } else {
+            eprintln!("{e}.");
+        }
+        process::exit(1);
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
@@ -32,9 +61,46 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
     }
+
+    // NOTE: failed steps are stored like the others, but still fail the command.
+    if !summary.failed.is_empty() {
+        process::exit(1);
+    }
+}
+
+/// Print how every step changed compared to the stored timings.
+/// Fails if any step got slower by more than `threshold` percent, or if nothing could be compared.
+fn compare_timings(stored: &Timings, new: &Timings, threshold: f64) -> Result<(), String> {
+    let changes = stored.compare(new);
+
+    println!("\n{ANSI_BOLD}Compared to stored timings:{ANSI_RESET}");
+
+    // NOTE: a comparison of nothing must not pass as a comparison without regressions.
+    if changes.is_empty() {
+        return Err(
+            "No stored timings to compare with, store some with `cargo time --store`".into(),
+        );
+    }
+
+    let mut is_success = true;
+
+    for change in changes {
+        if change.is_regression(threshold) {
+            is_success = false;
+            println!("{change} ✘");
+        } else {
+            println!("{change}");
+        }
+    }
+
+    if is_success {
+        Ok(())
+    } else {
+        Err(format!("Benchmarks regressed by more than {threshold}%"))
+    }
 }
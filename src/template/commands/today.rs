@@ -0,0 +1,49 @@
+use std::{process, thread, time::Duration};
+
+use crate::template::Day;
+use crate::template::aoc_client::{self, Retry};
+use crate::template::commands::{read, scaffold};
+
+/// Waited on top of the unlock, so that the clients counting down don't all fetch at the same instant.
+const UNLOCK_JITTER: Duration = Duration::from_secs(3);
+
+pub fn handle(wait: bool) {
+    let day = if wait { wait_for_unlock() } else { today() };
+
+    // NOTE: a puzzle that just unlocked may not be served yet.
+    let retry = if wait {
+        Retry::Unlock
+    } else {
+        Retry::Transient
+    };
+    scaffold::handle(day, false, Some(retry));
+    read::handle(day);
+}
+
+fn today() -> Day {
+    match Day::today() {
+        Some(day) => day,
+        None => {
+            eprintln!(
+                "`today` command can only be run between the 1st and \
+                the 25th of december. Please use `scaffold` with a specific day, \
+                or pass `--wait` to wait for the next one to unlock."
+            );
+            process::exit(1)
+        }
+    }
+}
+
+/// Counts down to the unlock of the next day and returns it once it is unlocked.
+fn wait_for_unlock() -> Day {
+    let Some((day, remaining)) = Day::next_unlock() else {
+        eprintln!(
+            "Every day of this year's advent is unlocked. Please use `scaffold` with a specific day."
+        );
+        process::exit(1)
+    };
+
+    aoc_client::countdown(remaining, &format!("Day {day} unlocks in"));
+    thread::sleep(aoc_client::jitter(UNLOCK_JITTER));
+    day
+}
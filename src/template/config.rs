@@ -0,0 +1,280 @@
+/// Project configuration, read once from `aoc.toml` at the root of the repository.
+/// Every setting can be overridden with an environment variable, e.g. `year` with `AOC_YEAR`.
+use std::{env, fs, io, path::PathBuf, process, str::FromStr, sync::OnceLock, time::Duration};
+
+use toml::{Table, Value};
+
+use crate::template::Year;
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+/// Every setting, by its section and key in `aoc.toml`, and the environment variable overriding it.
+const SETTINGS: [(Option<&str>, &str, &str); 10] = [
+    (None, "year", "AOC_YEAR"),
+    (None, "data_root", "AOC_DATA_ROOT"),
+    (None, "readme", "AOC_README"),
+    (Some("defaults"), "release", "AOC_RELEASE"),
+    (Some("defaults"), "memory", "AOC_MEMORY"),
+    (Some("defaults"), "jobs", "AOC_JOBS"),
+    (Some("limits"), "timeout", "AOC_TIMEOUT"),
+    (Some("limits"), "memory_limit", "AOC_MEMORY_LIMIT"),
+    (Some("bench"), "threshold", "AOC_BENCH_THRESHOLD"),
+    (Some("bench"), "slow", "AOC_BENCH_SLOW"),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Default year of the puzzles.
+    pub year: Option<Year>,
+    /// Folder of the inputs, examples, puzzles and answers.
+    pub data_root: PathBuf,
+    /// Readme holding the stars and benchmarks tables.
+    pub readme: PathBuf,
+    /// Build solutions optimized, as if `--release` was passed.
+    pub release: bool,
+    /// Measure heap usage, as if `--memory` was passed.
+    pub memory: bool,
+    /// Number of days `all` runs concurrently, unless `--jobs` is passed. Benchmarks ignore it.
+    pub jobs: usize,
+    /// Timeout of solution runs, unless `--timeout` is passed.
+    pub timeout: Option<Duration>,
+    /// Memory limit of solution runs in MiB, unless `--memory-limit` is passed.
+    pub memory_limit: Option<u64>,
+    /// Slowdown in percent above which `time --compare` reports a regression, unless `--threshold` is passed.
+    pub threshold: f64,
+    /// Stored timings above which a day counts as `slow`.
+    pub slow: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            data_root: PathBuf::from("data"),
+            readme: PathBuf::from("README.md"),
+            release: false,
+            memory: false,
+            jobs: 1,
+            timeout: None,
+            memory_limit: None,
+            threshold: 10.0,
+            slow: Duration::from_secs(1),
+        }
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Returns the configuration, reading it on first use. Exits if it is invalid.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| {
+        read().unwrap_or_else(|e| {
+            eprintln!("Error: invalid configuration in `{CONFIG_PATH}`: {e}");
+            process::exit(1);
+        })
+    })
+}
+
+/// Reads `aoc.toml` and the environment. Without `aoc.toml`, the defaults apply.
+fn read() -> Result<Config, String> {
+    let file = match fs::read_to_string(CONFIG_PATH) {
+        Ok(file) => Some(file),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e.to_string()),
+    };
+
+    parse(file.as_deref(), |var| env::var(var).ok())
+}
+
+fn parse(file: Option<&str>, env: impl Fn(&str) -> Option<String>) -> Result<Config, String> {
+    let table = match file {
+        Some(file) => file.parse::<Table>().map_err(|e| e.to_string())?,
+        None => Table::new(),
+    };
+
+    check_keys(&table)?;
+
+    let sources = Sources { table, env };
+    let defaults = Config::default();
+
+    Ok(Config {
+        year: sources.get("AOC_YEAR")?,
+        data_root: sources.get("AOC_DATA_ROOT")?.unwrap_or(defaults.data_root),
+        readme: sources.get("AOC_README")?.unwrap_or(defaults.readme),
+        release: sources.get("AOC_RELEASE")?.unwrap_or(defaults.release),
+        memory: sources.get("AOC_MEMORY")?.unwrap_or(defaults.memory),
+        jobs: sources.get("AOC_JOBS")?.unwrap_or(defaults.jobs),
+        timeout: sources
+            .get("AOC_TIMEOUT")?
+            .map(seconds)
+            .transpose()?
+            .or(defaults.timeout),
+        memory_limit: sources.get("AOC_MEMORY_LIMIT")?.or(defaults.memory_limit),
+        threshold: sources
+            .get("AOC_BENCH_THRESHOLD")?
+            .unwrap_or(defaults.threshold),
+        slow: sources
+            .get("AOC_BENCH_SLOW")?
+            .map(seconds)
+            .transpose()?
+            .unwrap_or(defaults.slow),
+    })
+}
+
+/// Rejects keys that are not settings, e.g. misspelled ones.
+fn check_keys(table: &Table) -> Result<(), String> {
+    let is_setting = |section: Option<&str>, key: &str| {
+        SETTINGS.iter().any(|(s, k, _)| *s == section && *k == key)
+    };
+
+    for (name, value) in table {
+        match value.as_table() {
+            Some(section) => {
+                if let Some(key) = section.keys().find(|key| !is_setting(Some(name), key)) {
+                    return Err(format!("unknown setting `{name}.{key}`"));
+                }
+            }
+            None if !is_setting(None, name) => return Err(format!("unknown setting `{name}`")),
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// The values of the settings, in the environment or else in `aoc.toml`.
+struct Sources<F> {
+    table: Table,
+    env: F,
+}
+
+impl<F: Fn(&str) -> Option<String>> Sources<F> {
+    fn get<T: FromStr>(&self, var: &str) -> Result<Option<T>, String> {
+        let (section, key, _) = SETTINGS
+            .iter()
+            .find(|(_, _, x)| *x == var)
+            .expect("unknown setting");
+
+        let (value, name) = match (self.env)(var).filter(|x| !x.trim().is_empty()) {
+            Some(value) => (value, format!("`{var}`")),
+            None => {
+                let table = match section {
+                    Some(section) => self.table.get(*section).and_then(Value::as_table),
+                    None => Some(&self.table),
+                };
+
+                let Some(value) = table.and_then(|table| table.get(*key)) else {
+                    return Ok(None);
+                };
+
+                let name = section.map_or_else(|| format!("`{key}`"), |x| format!("`{x}.{key}`"));
+                (
+                    to_string(value).ok_or_else(|| format!("{name} has an invalid type"))?,
+                    name,
+                )
+            }
+        };
+
+        value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| format!("{name} has an invalid value `{value}`"))
+    }
+}
+
+fn to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(x) => Some(x.clone()),
+        Value::Integer(x) => Some(x.to_string()),
+        Value::Float(x) => Some(x.to_string()),
+        Value::Boolean(x) => Some(x.to_string()),
+        _ => None,
+    }
+}
+
+fn seconds(secs: f64) -> Result<Duration, String> {
+    Duration::try_from_secs_f64(secs).map_err(|_| format!("`{secs}` is not a number of seconds"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Config, parse};
+    use std::{path::PathBuf, time::Duration};
+
+    const FILE: &str = r#"
+year = 2024
+data_root = "data/alt"
+
+[defaults]
+jobs = 4
+
+[limits]
+timeout = 2.5
+
+[bench]
+slow = 0.5
+"#;
+
+    #[test]
+    fn reads_settings() {
+        let config = parse(Some(FILE), |_| None).unwrap();
+
+        assert_eq!(config.year, "2024".parse().ok());
+        assert_eq!(config.data_root, PathBuf::from("data/alt"));
+        assert_eq!(config.readme, PathBuf::from("README.md"));
+        assert_eq!(config.jobs, 4);
+        assert_eq!(config.timeout, Some(Duration::from_millis(2500)));
+        assert_eq!(config.slow, Duration::from_millis(500));
+        assert_eq!(config.threshold, 10.0);
+    }
+
+    #[test]
+    fn defaults_without_a_file() {
+        assert_eq!(parse(None, |_| None).unwrap(), Config::default());
+    }
+
+    #[test]
+    fn overrides_settings_with_env_vars() {
+        let env = |var: &str| match var {
+            "AOC_YEAR" => Some("2016".to_string()),
+            "AOC_JOBS" => Some("8".to_string()),
+            "AOC_RELEASE" => Some("true".to_string()),
+            "AOC_README" => Some(String::new()),
+            _ => None,
+        };
+
+        let config = parse(Some(FILE), env).unwrap();
+        assert_eq!(config.year, "2016".parse().ok());
+        assert_eq!(config.jobs, 8);
+        assert!(config.release);
+        assert_eq!(config.readme, PathBuf::from("README.md"));
+        assert_eq!(config.data_root, PathBuf::from("data/alt"));
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        let error = |file: &str| parse(Some(file), |_| None).unwrap_err();
+
+        assert_eq!(error("yaer = 2024"), "unknown setting `yaer`");
+        assert_eq!(error("[bench]\nslwo = 1"), "unknown setting `bench.slwo`");
+        assert_eq!(error("year = 2014"), "`year` has an invalid value `2014`");
+        assert_eq!(
+            error("[defaults]\njobs = [1]"),
+            "`defaults.jobs` has an invalid type"
+        );
+        assert_eq!(
+            error("[limits]\ntimeout = -1"),
+            "`-1` is not a number of seconds"
+        );
+        assert!(parse(Some("year = "), |_| None).is_err());
+
+        let env = |_: &str| Some("many".to_string());
+        assert_eq!(
+            parse(None, env).unwrap_err(),
+            "`AOC_YEAR` has an invalid value `many`"
+        );
+    }
+}
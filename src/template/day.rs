@@ -2,10 +2,14 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
-
 #[cfg(feature = "today")]
+use std::time::Duration;
+
+/// Puzzles unlock at midnight in the timezone of the website, UTC-5.
 const SERVER_UTC_OFFSET: i32 = -5;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
@@ -31,24 +35,73 @@ impl Day {
         Some(Self(day))
     }
 
+    /// Creates a [`Day`] if the event of the configured year at build time has it,
+    /// returns [`None`] otherwise. See [`Year::CONFIGURED`].
+    pub const fn new_configured(day: u8) -> Option<Self> {
+        match (Self::new(day), Year::CONFIGURED) {
+            (Some(day), Some(year)) if day.0 > year.days() => None,
+            (day, _) => day,
+        }
+    }
+
+    /// Creates a [`Day`] if the event of a year has it, returns [`None`] otherwise.
+    pub const fn new_in(day: u8, year: u16) -> Option<Self> {
+        match (Self::new(day), Year::new(year)) {
+            (Some(day), Some(year)) if day.0 <= year.days() => Some(day),
+            _ => None,
+        }
+    }
+
     /// Converts the [`Day`] into an [`u8`].
-    pub fn into_inner(self) -> u8 {
+    pub const fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Returns the Unix timestamp at which the puzzle of this day unlocks in a year.
+    pub fn unlock_time(self, year: u16) -> i64 {
+        days_from_civil(i64::from(year), 12, i64::from(self.0)) * 86400
+            - i64::from(SERVER_UTC_OFFSET) * 3600
+    }
+}
+
+/// Days from the Unix epoch to a date of the Gregorian calendar.
+/// See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
 }
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's a day of this year's event in december, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        if today.month() == 12 && today.day() <= u32::from(year.days()) {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
         }
     }
+
+    /// Returns the next day to unlock this december and how long until it does, `None` once all are unlocked.
+    pub fn next_unlock() -> Option<(Self, Duration)> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let now = Utc::now().with_timezone(&offset);
+        let year = Year::new(u16::try_from(now.year()).ok()?)?;
+        let now = now.timestamp_millis();
+
+        year.all_days().find_map(|day| {
+            let remaining = day.unlock_time(year.into_inner()) * 1000 - now;
+            let remaining = Duration::from_millis(u64::try_from(remaining).ok()?);
+            Some((day, remaining))
+        })
+    }
 }
 
 impl Display for Day {
@@ -76,7 +129,9 @@ impl FromStr for Day {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        Self::new(day)
+            .filter(|day| day.0 <= last_day())
+            .ok_or(DayFromStrError)
     }
 }
 
@@ -88,26 +143,37 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {}", last_day())
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// The last day of the event of the current year, or the 25th if there is none.
+fn last_day() -> u8 {
+    Year::current().map_or(25, Year::days)
+}
+
+/// An iterator that yields every day of the event of the current year, see [`Year::current`].
+/// If there is none, the days from the 1st to the 25th.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent from the 1st to a last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::until(last_day())
+    }
+
+    pub(crate) fn until(last: u8) -> Self {
+        Self { current: 1, last }
     }
 }
 
@@ -115,10 +181,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and the last day of an event is not above 25.
         let day = Day(self.current);
         self.current += 1;
 
@@ -129,12 +195,20 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+/// The day has to be part of the event of the configured year, i.e. 1 to 12 from 2025 on,
+/// or of the year given before it.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {
         const {
-            $crate::template::Day::new($day)
-                .expect("invalid day number, expecting a value between 1 and 25")
+            $crate::template::Day::new_configured($day)
+                .expect("invalid day number, expecting a day of the event of the configured year")
+        }
+    };
+    ($year:expr, $day:expr) => {
+        const {
+            $crate::template::Day::new_in($day, $year)
+                .expect("invalid day number, expecting a day of the event of the year")
         }
     };
 }
@@ -143,11 +217,12 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::Day;
+    use crate::template::Year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = Year::new(2024).unwrap().all_days();
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -176,6 +251,12 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn computes_unlock_times() {
+        assert_eq!(Day(1).unlock_time(2025), 1_764_565_200);
+        assert_eq!(Day(25).unlock_time(2015), 1_451_019_600);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,276 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::template::report::Status;
+use crate::template::timings::Timings;
+use crate::template::{Day, DayFromStrError, Year, all_days, answers, config};
+
+/// A set of days of the current year, given as a comma separated list of terms:
+/// - a day, e.g. `7`
+/// - a range of days, which excludes its end like Rust's ranges, e.g. `1..5`, `1..=5`, `..5` or `10..`,
+///   so that the day after the last one may end it, e.g. `1..13` in a year of 12 days
+/// - `odd` or `even` days
+/// - `unsolved` days, which lack the accepted answer of a part. The last day of an event only has one.
+/// - `slow` days, whose stored timings take more than `bench.slow` of `aoc.toml` or timed out
+///
+/// A day is in the set if any of the terms matches it, e.g. `1..5,7,10..`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet {
+    terms: Vec<Term>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Term {
+    Day(Day),
+    /// Days from `start` up to and including `end`.
+    Range {
+        start: u8,
+        end: u8,
+    },
+    Odd,
+    Even,
+    Unsolved,
+    Slow,
+}
+
+impl DaySet {
+    /// Returns the days of the set, for `run_multi`.
+    #[must_use]
+    pub fn days(&self) -> HashSet<Day> {
+        self.filter(Year::current())
+    }
+
+    /// Returns the days of the event of a year that are in the set, or of days 1 to 25 without one.
+    fn filter(&self, year: Option<Year>) -> HashSet<Day> {
+        let (days, last) = match year {
+            Some(year) => (year.all_days(), year.days()),
+            None => (all_days(), 25),
+        };
+
+        // NOTE: timings are only read if the set asks for them.
+        let timings = self
+            .terms
+            .contains(&Term::Slow)
+            .then(Timings::read_from_file);
+
+        days.filter(|day| {
+            self.terms
+                .iter()
+                .any(|term| term.matches(*day, last, timings.as_ref()))
+        })
+        .collect()
+    }
+}
+
+impl Term {
+    fn matches(self, day: Day, last: u8, timings: Option<&Timings>) -> bool {
+        match self {
+            Term::Day(x) => x == day,
+            Term::Range { start, end } => (start..=end).contains(&day.into_inner()),
+            Term::Odd => !day.into_inner().is_multiple_of(2),
+            Term::Even => day.into_inner().is_multiple_of(2),
+            Term::Unsolved => parts(day, last)
+                .iter()
+                .any(|part| answers::read(day, *part).is_none()),
+            Term::Slow => timings.is_some_and(|timings| is_slow(timings, day, config::get().slow)),
+        }
+    }
+}
+
+/// Returns the parts of a day with an answer to submit. The last day of an event only has one,
+/// its second star is given for the others.
+fn parts(day: Day, last: u8) -> &'static [u8] {
+    if day == last { &[1] } else { &[1, 2] }
+}
+
+fn is_slow(timings: &Timings, day: Day, threshold: Duration) -> bool {
+    timings.data.iter().any(|timing| {
+        timing.day == day
+            && (timing.total_nanos > threshold.as_nanos() as f64
+                || timing
+                    .failures
+                    .iter()
+                    .any(|(_, status)| *status == Status::TimedOut))
+    })
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DaySet::parse_in(s, Year::current())
+    }
+}
+
+impl DaySet {
+    /// Parses a set of days of a year, or of days 1 to 25 without one.
+    fn parse_in(s: &str, year: Option<Year>) -> Result<Self, DaySetFromStrError> {
+        let last = year.map_or(25, Year::days);
+
+        let terms = s
+            .split(',')
+            .map(|term| parse_term(term.trim(), last))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { terms })
+    }
+}
+
+fn parse_term(s: &str, last: u8) -> Result<Term, DaySetFromStrError> {
+    match s {
+        "odd" => return Ok(Term::Odd),
+        "even" => return Ok(Term::Even),
+        "unsolved" => return Ok(Term::Unsolved),
+        "slow" => return Ok(Term::Slow),
+        "" => return Err(DaySetFromStrError::Empty),
+        _ => {}
+    }
+
+    let Some((start, end)) = s.split_once("..") else {
+        return Ok(Term::Day(parse_day(s, last)?));
+    };
+
+    let start = match start {
+        "" => 1,
+        x => parse_day(x, last)?.into_inner(),
+    };
+
+    // NOTE: like Rust's ranges, an exclusive range may end right after the last day, e.g. `1..13`.
+    let end = match end.strip_prefix('=') {
+        Some(x) => parse_day(x, last)?.into_inner(),
+        None if end.is_empty() => u8::MAX,
+        None if end.parse() == Ok(last + 1) => last,
+        None => parse_day(end, last)?.into_inner() - 1,
+    };
+
+    if start > end {
+        return Err(DaySetFromStrError::EmptyRange(s.to_string()));
+    }
+
+    Ok(Term::Range { start, end })
+}
+
+fn parse_day(s: &str, last: u8) -> Result<Day, DaySetFromStrError> {
+    s.parse()
+        .ok()
+        .and_then(Day::new)
+        .filter(|day| day.into_inner() <= last)
+        .ok_or_else(|| DaySetFromStrError::Day(s.to_string(), DayFromStrError))
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub enum DaySetFromStrError {
+    Empty,
+    EmptyRange(String),
+    Day(String, DayFromStrError),
+}
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DaySetFromStrError::Empty => {
+                f.write_str("expecting days, e.g. `1..5,7,10..`, `odd`, `unsolved` or `slow`")
+            }
+            DaySetFromStrError::EmptyRange(range) => write!(f, "the range `{range}` has no days"),
+            DaySetFromStrError::Day(day, e) => write!(f, "`{day}`: {e}"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DaySet, is_slow, parts};
+    use crate::day;
+    use crate::template::Year;
+    use crate::template::report::{Status, Step};
+    use crate::template::timings::{Timing, Timings};
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    fn parse(s: &str, year: u16) -> Result<DaySet, super::DaySetFromStrError> {
+        DaySet::parse_in(s, Year::new(year))
+    }
+
+    fn days(s: &str, year: u16) -> HashSet<u8> {
+        let year = Year::new(year).unwrap();
+        let set = parse(s, year.into_inner()).unwrap();
+        set.filter(Some(year))
+            .into_iter()
+            .map(|day| day.into_inner())
+            .collect()
+    }
+
+    #[test]
+    fn parses_days_and_ranges() {
+        assert_eq!(days("7", 2025), HashSet::from([7]));
+        assert_eq!(
+            days("1..5,7,10..", 2025),
+            HashSet::from([1, 2, 3, 4, 7, 10, 11, 12])
+        );
+        assert_eq!(days("..=3, 12", 2025), HashSet::from([1, 2, 3, 12]));
+        assert_eq!(days("2..3", 2025), HashSet::from([2]));
+        assert_eq!(days("odd", 2025).len(), 6);
+        assert_eq!(days("odd", 2024).len(), 13);
+        assert_eq!(days("even,1", 2025), days("..=2,4,6,8,10,12", 2025));
+        assert_eq!(days("20..", 2024), HashSet::from([20, 21, 22, 23, 24, 25]));
+    }
+
+    #[test]
+    fn accepts_ranges_ending_after_the_last_day() {
+        assert_eq!(days("1..13", 2025).len(), 12);
+        assert_eq!(days("1..26", 2024).len(), 25);
+        assert_eq!(days("10..13", 2025), HashSet::from([10, 11, 12]));
+    }
+
+    #[test]
+    fn rejects_invalid_sets() {
+        assert!(parse("", 2025).is_err());
+        assert!(parse("1,,2", 2025).is_err());
+        assert!(parse("3..3", 2025).is_err());
+        assert!(parse("5..=2", 2025).is_err());
+        assert!(parse("1..14", 2025).is_err());
+        assert!(parse("1..=13", 2025).is_err());
+        assert!(parse("13", 2025).is_err());
+        assert!(parse("13", 2024).is_ok());
+        assert!(parse("fast", 2025).is_err());
+    }
+
+    #[test]
+    fn checks_one_part_on_the_last_day() {
+        assert_eq!(parts(day!(11), 12), [1, 2]);
+        assert_eq!(parts(day!(12), 12), [1]);
+        assert_eq!(parts(day!(12), 25), [1, 2]);
+    }
+
+    #[test]
+    fn finds_slow_days() {
+        let timing = |day, total_nanos, failures| Timing {
+            failures,
+            total_nanos,
+            ..Timing::new(day)
+        };
+
+        let timings = Timings {
+            data: vec![
+                timing(day!(1), 5e8, vec![]),
+                timing(day!(2), 2e9, vec![]),
+                timing(day!(3), 0.0, vec![(Step::Part(2), Status::TimedOut)]),
+            ],
+        };
+
+        let threshold = Duration::from_secs(1);
+        assert!(!is_slow(&timings, day!(1), threshold));
+        assert!(is_slow(&timings, day!(2), threshold));
+        assert!(is_slow(&timings, day!(3), threshold));
+        assert!(!is_slow(&timings, day!(4), threshold));
+        assert!(is_slow(&timings, day!(1), Duration::from_millis(100)));
+    }
+}
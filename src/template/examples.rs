@@ -0,0 +1,132 @@
+/// Finds the examples of puzzle descriptions, used to scaffold example files and tests.
+/// The example of a part is the first code block after "For example", its expected answer the last
+/// emphasized code of the part, as the puzzles tend to be worded.
+use crate::template::{aoc_client, markdown};
+
+/// The example of a part, as far as it could be found.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Extracts the example of every part described on a puzzle page.
+pub fn extract(html: &str) -> Vec<Example> {
+    aoc_client::articles(html)
+        .iter()
+        .map(|article| Example {
+            input: find_input(article),
+            answer: find_answer(article),
+        })
+        .collect()
+}
+
+/// Returns the distinct inputs of examples in order, and the index of the input used by each part.
+/// Parts without an example of their own use the first input, as part two mostly reuses the example of part one.
+pub fn distinct_inputs(examples: &[Example], parts: usize) -> (Vec<String>, Vec<usize>) {
+    let mut inputs: Vec<String> = vec![];
+
+    let indices = (0..parts)
+        .map(|part| {
+            let Some(input) = examples.get(part).and_then(|x| x.input.as_ref()) else {
+                return 0;
+            };
+
+            inputs.iter().position(|x| x == input).unwrap_or_else(|| {
+                inputs.push(input.clone());
+                inputs.len() - 1
+            })
+        })
+        .collect();
+
+    (inputs, indices)
+}
+
+fn find_input(article: &str) -> Option<String> {
+    let start = article.find("For example")?;
+    let code = between(&article[start..], "<pre><code>", "</code></pre>")?;
+    let input = markdown::text(code);
+
+    if input.ends_with('\n') {
+        Some(input)
+    } else {
+        Some(input + "\n")
+    }
+}
+
+fn find_answer(article: &str) -> Option<String> {
+    let start = article.rfind("<code><em>")?;
+    let answer = between(&article[start..], "<code><em>", "</em></code>")?;
+    Some(markdown::text(answer).trim().to_string()).filter(|x| !x.is_empty())
+}
+
+fn between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = s.find(start)? + start.len();
+    let to = s[from..].find(end)? + from;
+    Some(&s[from..to])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, distinct_inputs, extract};
+
+    const PAGE: &str = "<main>\
+        <article class=\"day-desc\"><h2>--- Day 1 ---</h2>\
+        <p>Like this:</p><pre><code>not it</code></pre>\
+        <p>For example:</p>\n<pre><code>1abc2\n<em>pqr</em>3 &lt;&gt;\n</code></pre>\
+        <p>Adding these gives <code><em>12</em></code> and <code><em>142</em></code>.</p></article>\
+        <p>Your puzzle answer was <code>54388</code>.</p>\
+        <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\
+        <p>For example:</p><pre><code>two1nine\n</code></pre>\
+        <p>The sum is <code><em>281</em></code>. What is it?</p></article>\
+        </main>";
+
+    #[test]
+    fn extracts_examples() {
+        assert_eq!(
+            extract(PAGE),
+            vec![
+                Example {
+                    input: Some("1abc2\npqr3 <>\n".into()),
+                    answer: Some("142".into()),
+                },
+                Example {
+                    input: Some("two1nine\n".into()),
+                    answer: Some("281".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_missing_examples() {
+        assert_eq!(
+            extract("<article><p>No <code>code</code> here.</p></article>"),
+            vec![Example::default()]
+        );
+    }
+
+    #[test]
+    fn deduplicates_inputs() {
+        let example = |input: Option<&str>| Example {
+            input: input.map(String::from),
+            answer: None,
+        };
+
+        assert_eq!(
+            distinct_inputs(&[example(Some("a")), example(Some("b"))], 2),
+            (vec!["a".into(), "b".into()], vec![0, 1])
+        );
+        assert_eq!(
+            distinct_inputs(&[example(Some("a")), example(Some("a"))], 2),
+            (vec!["a".into()], vec![0, 0])
+        );
+        assert_eq!(
+            distinct_inputs(&[example(Some("a"))], 2),
+            (vec!["a".into()], vec![0, 0])
+        );
+        assert_eq!(distinct_inputs(&[], 2), (vec![], vec![0, 0]));
+    }
+}
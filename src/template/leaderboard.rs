@@ -0,0 +1,600 @@
+/// Private leaderboards of Advent of Code: fetching and caching the JSON of the website, and rendering it
+/// for the terminal, as Markdown or as JSON. Fetched leaderboards are cached in `data/leaderboards`.
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::AocClient;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, profile};
+
+/// How long a fetched leaderboard is reused, as the website asks not to fetch them more often.
+const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// The stars of a member on a day, as Unix timestamps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DayStars {
+    pub part_1: Option<u64>,
+    pub part_2: Option<u64>,
+}
+
+impl DayStars {
+    /// Time between the two stars, in seconds.
+    pub fn delta(&self) -> Option<u64> {
+        Some(self.part_2?.saturating_sub(self.part_1?))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    pub last_star_ts: u64,
+    /// Days with at least one star, in order.
+    pub days: Vec<(Day, DayStars)>,
+}
+
+impl Member {
+    /// The name of the member, or how the website shows anonymous members.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn day(&self, day: Day) -> DayStars {
+        self.days
+            .iter()
+            .find(|(x, _)| *x == day)
+            .map(|(_, stars)| *stars)
+            .unwrap_or_default()
+    }
+}
+
+/// A leaderboard, its members ranked by local score.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// The last day any member got a star on.
+    pub fn last_day(&self) -> Option<Day> {
+        self.members
+            .iter()
+            .filter_map(|member| member.days.last().map(|(day, _)| *day))
+            .max_by_key(|day| day.into_inner())
+    }
+
+    /// Days any member got a star on, in order.
+    fn days(&self) -> Vec<Day> {
+        let mut days: Vec<Day> = self
+            .members
+            .iter()
+            .flat_map(|member| member.days.iter().map(|(day, _)| *day))
+            .collect();
+
+        days.sort_by_key(|day| day.into_inner());
+        days.dedup();
+        days
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the path to the cached JSON of a leaderboard.
+#[must_use]
+pub fn get_cache_path(year: u16, id: &str) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join(profile::data_dir("leaderboards"))
+        .join(format!("{year}-{id}.json"))
+}
+
+/// Loads a leaderboard, from the cache if it was fetched less than 15 minutes ago.
+/// Returns the leaderboard and the age of the cache it was read from, if it was.
+pub fn load(client: &AocClient, id: &str) -> Result<(Leaderboard, Option<Duration>), String> {
+    let path = get_cache_path(client.year(), id);
+
+    let cached = fs::read_to_string(&path).ok().and_then(|json| {
+        let age = fs::metadata(&path)
+            .and_then(|x| x.modified())
+            .ok()
+            .and_then(|x| SystemTime::now().duration_since(x).ok())?;
+        Some((json, age))
+    });
+
+    if let Some((json, age)) = &cached
+        && *age < CACHE_TTL
+    {
+        return Ok((Leaderboard::try_from(json.as_str())?, Some(*age)));
+    }
+
+    let json = match client.leaderboard(id) {
+        Ok(json) => json,
+        Err(e) => {
+            let Some((json, age)) = cached else {
+                return Err(e.to_string());
+            };
+
+            eprintln!("Could not refresh the leaderboard, showing the cached one: {e}");
+            return Ok((Leaderboard::try_from(json.as_str())?, Some(age)));
+        }
+    };
+
+    // NOTE: the website redirects to its login page if the session can't view the leaderboard.
+    let leaderboard = Leaderboard::try_from(json.as_str()).map_err(|e| {
+        format!("{e} Check the ID of the leaderboard and that your session cookie can view it.")
+    })?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    fs::write(&path, json).map_err(|e| e.to_string())?;
+    Ok((leaderboard, None))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders the rankings, with a column of stars per day.
+pub fn format_rankings(leaderboard: &Leaderboard) -> String {
+    let days = leaderboard.days();
+    let name_width = name_width(leaderboard);
+
+    let mut lines = vec![format!(
+        "{ANSI_BOLD}{:>3}  {:>5}  {:>5}  {:<name_width$}  {}{ANSI_RESET}",
+        "#",
+        "Score",
+        "Stars",
+        "Name",
+        days.iter()
+            .map(|day| format!("{:>2}", day.into_inner()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    )];
+
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        let stars: Vec<String> = days
+            .iter()
+            .map(|day| format!(" {}", star(member.day(*day))))
+            .collect();
+
+        lines.push(format!(
+            "{:>3}  {:>5}  {:>5}  {:<name_width$}  {}",
+            format!("{}.", rank + 1),
+            member.local_score,
+            member.stars,
+            member.display_name(),
+            stars.join(" ")
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Renders when every member got the stars of a day, counted from its unlock, and the time between both stars.
+pub fn format_day(leaderboard: &Leaderboard, day: Day) -> String {
+    let name_width = name_width(leaderboard);
+    let unlock = day.unlock_time(leaderboard.year);
+
+    let mut members: Vec<(&Member, DayStars)> = leaderboard
+        .members
+        .iter()
+        .map(|member| (member, member.day(day)))
+        .filter(|(_, stars)| stars.part_1.is_some())
+        .collect();
+
+    // NOTE: rank by the second star, then by the first one.
+    members.sort_by_key(|(_, stars)| (stars.part_2.unwrap_or(u64::MAX), stars.part_1));
+
+    let mut lines = vec![
+        format!("{ANSI_BOLD}Day {day}{ANSI_RESET}"),
+        format!(
+            "{ANSI_BOLD}{:<name_width$}  {:>10}  {:>10}  {:>10}{ANSI_RESET}",
+            "Name", "Part 1", "Part 2", "Delta"
+        ),
+    ];
+
+    if members.is_empty() {
+        lines.push(format!("{ANSI_ITALIC}No stars yet.{ANSI_RESET}"));
+    }
+
+    for (member, stars) in members {
+        lines.push(format!(
+            "{:<name_width$}  {:>10}  {:>10}  {:>10}",
+            member.display_name(),
+            format_elapsed(stars.part_1, unlock),
+            format_elapsed(stars.part_2, unlock),
+            stars.delta().map_or_else(|| "-".into(), format_seconds)
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Renders the rankings and the stars of every day as Markdown tables.
+pub fn to_markdown(leaderboard: &Leaderboard) -> String {
+    let days = leaderboard.days();
+
+    let mut lines = vec![
+        format!("## Leaderboard {}", leaderboard.year),
+        String::new(),
+        format!(
+            "| # | Name | Score | Stars |{}",
+            days.iter()
+                .map(|day| format!(" {} |", day.into_inner()))
+                .collect::<String>()
+        ),
+        format!(
+            "| ---: | :--- | ---: | ---: |{}",
+            " :---: |".repeat(days.len())
+        ),
+    ];
+
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        lines.push(format!(
+            "| {} | {} | {} | {} |{}",
+            rank + 1,
+            escape_markdown(&member.display_name()),
+            member.local_score,
+            member.stars,
+            days.iter()
+                .map(|day| format!(" {} |", star(member.day(*day))))
+                .collect::<String>()
+        ));
+    }
+
+    for day in days.iter().rev() {
+        let unlock = day.unlock_time(leaderboard.year);
+
+        lines.push(String::new());
+        lines.push(format!("### Day {}", day.into_inner()));
+        lines.push(String::new());
+        lines.push("| Name | Part 1 | Part 2 | Delta |".into());
+        lines.push("| :--- | ---: | ---: | ---: |".into());
+
+        for member in &leaderboard.members {
+            let stars = member.day(*day);
+
+            if stars.part_1.is_some() {
+                lines.push(format!(
+                    "| {} | {} | {} | {} |",
+                    escape_markdown(&member.display_name()),
+                    format_elapsed(stars.part_1, unlock),
+                    format_elapsed(stars.part_2, unlock),
+                    stars.delta().map_or_else(|| "-".into(), format_seconds)
+                ));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn name_width(leaderboard: &Leaderboard) -> usize {
+    leaderboard
+        .members
+        .iter()
+        .map(|member| member.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4)
+}
+
+/// `★` for both stars of a day, `☆` for the first one only.
+fn star(stars: DayStars) -> char {
+    match (stars.part_1, stars.part_2) {
+        (Some(_), Some(_)) => '★',
+        (Some(_), None) => '☆',
+        _ => '·',
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+/// Formats the time from the unlock of a puzzle to a star, as the website does in personal stats.
+fn format_elapsed(timestamp: Option<u64>, unlock: i64) -> String {
+    match timestamp {
+        Some(timestamp) => format_seconds((timestamp as i64 - unlock).max(0) as u64),
+        None => "-".into(),
+    }
+}
+
+/// Formats seconds as e.g. `01:02:03`, or in days past 24 hours.
+fn format_seconds(seconds: u64) -> String {
+    if seconds >= 24 * 3600 {
+        return format!(">{}d", seconds / (24 * 3600));
+    }
+
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<&str> for Leaderboard {
+    type Error = String;
+
+    /// Parses the JSON served by the website at `/{year}/leaderboard/private/view/{id}.json`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let map: &HashMap<String, JsonValue> = json
+            .get()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let event: &String = map
+            .get("event")
+            .and_then(|x| x.get())
+            .ok_or("expected event to be a string.")?;
+        let year = event.parse().or(Err("expected event to be a year."))?;
+
+        let members: &HashMap<String, JsonValue> = map
+            .get("members")
+            .and_then(|x| x.get())
+            .ok_or("expected members to be an object.")?;
+
+        let mut members = members
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+
+        Ok(Leaderboard { year, members })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let map: &HashMap<String, JsonValue> =
+            value.get().ok_or("expected member to be an object.")?;
+
+        // NOTE: members without stars have `null` timestamps.
+        let number = |key: &str| -> Result<u64, String> {
+            match map.get(key) {
+                Some(JsonValue::Number(x)) => Ok(*x as u64),
+                Some(JsonValue::Null) | None => Ok(0),
+                _ => Err(format!("expected {key} to be a number.")),
+            }
+        };
+
+        let name: Option<&String> = map.get("name").and_then(|x| x.get());
+
+        let completion: &HashMap<String, JsonValue> = map
+            .get("completion_day_level")
+            .and_then(|x| x.get())
+            .ok_or("expected completion_day_level to be an object.")?;
+
+        let mut days = completion
+            .iter()
+            .map(|(day, parts)| {
+                // NOTE: not parsed as a day of `AOC_YEAR`, as the leaderboard may be of another year.
+                let day = day
+                    .parse()
+                    .ok()
+                    .and_then(Day::new)
+                    .ok_or_else(|| format!("unexpected day {day}."))?;
+                let parts: &HashMap<String, JsonValue> =
+                    parts.get().ok_or("expected day to be an object.")?;
+
+                let star = |part: &str| -> Option<u64> {
+                    let part: &HashMap<String, JsonValue> = parts.get(part)?.get()?;
+                    let timestamp: &f64 = part.get("get_star_ts")?.get()?;
+                    Some(*timestamp as u64)
+                };
+
+                Ok((
+                    day,
+                    DayStars {
+                        part_1: star("1"),
+                        part_2: star("2"),
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        days.sort_by_key(|(day, _)| day.into_inner());
+
+        Ok(Member {
+            id: number("id")?,
+            name: name.cloned(),
+            local_score: number("local_score")?,
+            stars: number("stars")?,
+            last_star_ts: number("last_star_ts")?,
+            days,
+        })
+    }
+}
+
+impl From<&Leaderboard> for JsonValue {
+    /// Exports the leaderboard ranked, with the stars of every day and the time between them.
+    fn from(value: &Leaderboard) -> Self {
+        let members = value
+            .members
+            .iter()
+            .enumerate()
+            .map(|(rank, member)| {
+                let days = member
+                    .days
+                    .iter()
+                    .map(|(day, stars)| {
+                        let timestamp = |x: Option<u64>| {
+                            x.map_or(JsonValue::Null, |x| JsonValue::Number(x as f64))
+                        };
+
+                        (
+                            day.into_inner().to_string(),
+                            JsonValue::Object(HashMap::from([
+                                ("part_1".into(), timestamp(stars.part_1)),
+                                ("part_2".into(), timestamp(stars.part_2)),
+                                ("delta_seconds".into(), timestamp(stars.delta())),
+                            ])),
+                        )
+                    })
+                    .collect();
+
+                JsonValue::Object(HashMap::from([
+                    ("rank".into(), JsonValue::Number((rank + 1) as f64)),
+                    ("id".into(), JsonValue::Number(member.id as f64)),
+                    ("name".into(), JsonValue::String(member.display_name())),
+                    (
+                        "local_score".into(),
+                        JsonValue::Number(member.local_score as f64),
+                    ),
+                    ("stars".into(), JsonValue::Number(member.stars as f64)),
+                    ("days".into(), JsonValue::Object(days)),
+                ]))
+            })
+            .collect();
+
+        JsonValue::Object(HashMap::from([
+            ("year".into(), JsonValue::Number(f64::from(value.year))),
+            ("members".into(), JsonValue::Array(members)),
+        ]))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{DayStars, Leaderboard, format_day, format_rankings, to_markdown};
+    use crate::day;
+
+    /// Unlock of 2025 day 1.
+    const UNLOCK: u64 = 1_764_565_200;
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::try_from(
+            format!(
+                r#"{{
+                    "event": "2025",
+                    "owner_id": 1,
+                    "members": {{
+                        "1": {{
+                            "id": 1, "name": "Ada", "stars": 3, "local_score": 5, "global_score": 0,
+                            "last_star_ts": {last_1},
+                            "completion_day_level": {{
+                                "1": {{ "1": {{ "get_star_ts": {a}, "star_index": 1 }}, "2": {{ "get_star_ts": {b}, "star_index": 2 }} }},
+                                "2": {{ "1": {{ "get_star_ts": {c}, "star_index": 3 }} }}
+                            }}
+                        }},
+                        "2": {{
+                            "id": 2, "name": null, "stars": 2, "local_score": 5, "global_score": 0,
+                            "last_star_ts": {last_2},
+                            "completion_day_level": {{
+                                "1": {{ "1": {{ "get_star_ts": {d}, "star_index": 1 }}, "2": {{ "get_star_ts": {e}, "star_index": 2 }} }}
+                            }}
+                        }},
+                        "3": {{
+                            "id": 3, "name": "Bob", "stars": 0, "local_score": 0, "global_score": 0,
+                            "last_star_ts": 0, "completion_day_level": {{}}
+                        }}
+                    }}
+                }}"#,
+                a = UNLOCK + 61,
+                b = UNLOCK + 3_723,
+                c = UNLOCK + 86_400 + 10,
+                last_1 = UNLOCK + 86_400 + 10,
+                d = UNLOCK + 100,
+                e = UNLOCK + 200,
+                last_2 = UNLOCK + 200,
+            )
+            .as_str(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_leaderboards() {
+        let leaderboard = leaderboard();
+
+        assert_eq!(leaderboard.year, 2025);
+        // NOTE: ties in score are broken by stars.
+        let ids: Vec<u64> = leaderboard.members.iter().map(|x| x.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(
+            leaderboard.members[0].day(day!(2)),
+            DayStars {
+                part_1: Some(UNLOCK + 86_410),
+                part_2: None
+            }
+        );
+        assert_eq!(leaderboard.members[1].display_name(), "(anonymous user #2)");
+        assert_eq!(leaderboard.last_day(), Some(day!(2)));
+    }
+
+    #[test]
+    fn formats_rankings() {
+        let rankings = format_rankings(&leaderboard());
+        let lines: Vec<&str> = rankings.lines().collect();
+
+        assert!(lines[0].contains("Score"));
+        assert_eq!(lines[1], " 1.      5      3  Ada                   ★  ☆");
+        assert_eq!(lines[2], " 2.      5      2  (anonymous user #2)   ★  ·");
+        assert_eq!(lines[3], " 3.      0      0  Bob                   ·  ·");
+    }
+
+    #[test]
+    fn formats_days() {
+        let day = format_day(&leaderboard(), day!(1));
+        let lines: Vec<&str> = day.lines().collect();
+
+        assert_eq!(
+            lines[2],
+            "(anonymous user #2)    00:01:40    00:03:20    00:01:40"
+        );
+        assert_eq!(
+            lines[3],
+            "Ada                    00:01:01    01:02:03    01:01:02"
+        );
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn exports_markdown() {
+        let markdown = to_markdown(&leaderboard());
+
+        assert!(markdown.contains("| 1 | Ada | 5 | 3 | ★ | ☆ |"));
+        assert!(markdown.contains("### Day 2\n\n| Name | Part 1 | Part 2 | Delta |"));
+        assert!(markdown.contains("| Ada | 00:00:10 | - | - |"));
+    }
+
+    #[test]
+    fn exports_json() {
+        let json = JsonValue::from(&leaderboard());
+        let first = &json["members"][0];
+
+        assert_eq!(first["name"], JsonValue::String("Ada".into()));
+        assert_eq!(
+            first["days"]["1"]["delta_seconds"],
+            JsonValue::Number(3_662.0)
+        );
+        assert_eq!(first["days"]["2"]["part_2"], JsonValue::Null);
+    }
+}
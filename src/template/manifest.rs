@@ -0,0 +1,212 @@
+/// Manifest of downloaded inputs, used to notice inputs that changed or are about to be overwritten.
+/// It holds a content hash of the input of every day, in `data/inputs/manifest.json`.
+use std::{
+    collections::{BTreeMap, HashMap},
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, profile};
+
+/// Content hashes of the downloaded inputs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    hashes: BTreeMap<Day, String>,
+}
+
+impl Manifest {
+    /// Returns the hash of the input of a day, when it was downloaded.
+    pub fn get(&self, day: Day) -> Option<&str> {
+        self.hashes.get(&day).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: Day, hash: String) {
+        self.hashes.insert(day, hash);
+    }
+}
+
+/// Something worth knowing about a downloaded input, compared to the one on disk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Warning {
+    /// The input on disk is not the one that was downloaded.
+    Modified,
+    /// The website returned another input than the one downloaded before.
+    Changed,
+    /// A non-empty input on disk differs from the download, and is backed up before it is overwritten.
+    Overwritten,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::Modified => f.write_str("was edited since it was downloaded"),
+            Warning::Changed => f.write_str("differs from the input downloaded before"),
+            Warning::Overwritten => {
+                f.write_str("is not empty and is replaced with a different input, the old one is kept in a backup")
+            }
+        }
+    }
+}
+
+/// Returns the path that the previous contents of an overwritten input are kept at, e.g. `01.txt.bak`.
+#[must_use]
+pub fn get_backup_path(input_path: &Path) -> PathBuf {
+    let mut name = input_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    input_path.with_file_name(name)
+}
+
+#[must_use]
+pub fn get_path() -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join(profile::data_dir("inputs")).join("manifest.json")
+}
+
+/// Reads the manifest, which is empty until an input is downloaded.
+pub fn read() -> Result<Manifest, String> {
+    match fs::read_to_string(get_path()) {
+        Ok(json) => Manifest::try_from(json.as_str()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Manifest::default()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub fn write(manifest: &Manifest) -> Result<(), String> {
+    let path = get_path();
+    let json = JsonValue::from(manifest)
+        .format()
+        .map_err(|e| e.to_string())?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    fs::write(path, json + "\n").map_err(|e| e.to_string())
+}
+
+/// Hashes an input with 64-bit FNV-1a, which unlike the hashers of `std` is stable across releases.
+#[must_use]
+pub fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+/// Compares a downloaded input against the input on disk and the hash recorded for it.
+pub fn check(existing: Option<&str>, recorded: Option<&str>, downloaded: &str) -> Vec<Warning> {
+    let existing = existing.filter(|x| !x.trim().is_empty());
+    let mut warnings = vec![];
+
+    if let (Some(existing), Some(recorded)) = (existing, recorded)
+        && hash(existing) != recorded
+    {
+        warnings.push(Warning::Modified);
+    }
+
+    if recorded.is_some_and(|recorded| recorded != hash(downloaded)) {
+        warnings.push(Warning::Changed);
+    }
+
+    if existing.is_some_and(|existing| existing != downloaded) {
+        warnings.push(Warning::Overwritten);
+    }
+
+    warnings
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<&str> for Manifest {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = value.parse::<JsonValue>().map_err(|e| e.to_string())?;
+        let entries = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected the manifest to be an object.")?;
+
+        let mut manifest = Manifest::default();
+
+        for (day, hash) in entries {
+            let day = day.parse::<Day>().map_err(|e| format!("{day}: {e}"))?;
+            let hash = hash
+                .get::<String>()
+                .ok_or_else(|| format!("Expected the hash of day {day} to be a string."))?;
+            manifest.insert(day, hash.clone());
+        }
+
+        Ok(manifest)
+    }
+}
+
+impl From<&Manifest> for JsonValue {
+    fn from(value: &Manifest) -> Self {
+        JsonValue::Object(
+            value
+                .hashes
+                .iter()
+                .map(|(day, hash)| (day.to_string(), JsonValue::String(hash.clone())))
+                .collect(),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Manifest, Warning, check, get_backup_path, hash};
+    use crate::day;
+    use std::path::{Path, PathBuf};
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn backs_up_next_to_the_input() {
+        assert_eq!(
+            get_backup_path(Path::new("data/inputs/01.txt")),
+            PathBuf::from("data/inputs/01.txt.bak")
+        );
+    }
+
+    #[test]
+    fn hashes_inputs() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn checks_downloads() {
+        let old = "1\n2\n";
+        let new = "3\n4\n";
+
+        assert_eq!(check(None, None, new), vec![]);
+        assert_eq!(check(Some(""), None, new), vec![]);
+        assert_eq!(check(Some(new), Some(&hash(new)), new), vec![]);
+        assert_eq!(check(None, Some(&hash(old)), new), vec![Warning::Changed]);
+        assert_eq!(
+            check(Some(old), Some(&hash(old)), new),
+            vec![Warning::Changed, Warning::Overwritten]
+        );
+        assert_eq!(
+            check(Some("edited"), Some(&hash(new)), new),
+            vec![Warning::Modified, Warning::Overwritten]
+        );
+    }
+
+    #[test]
+    fn serializes_manifests() {
+        let mut manifest = Manifest::default();
+        manifest.insert(day!(1), hash("a"));
+        manifest.insert(day!(12), hash("b"));
+
+        let json = JsonValue::from(&manifest).stringify().unwrap();
+        assert_eq!(Manifest::try_from(json.as_str()), Ok(manifest));
+        assert!(Manifest::try_from("[]").is_err());
+    }
+}
@@ -0,0 +1,426 @@
+/// Converts the HTML of puzzle descriptions to Markdown.
+/// Only the markup used in `<article>` elements of Advent of Code is supported: headings, paragraphs,
+/// code blocks, inline code, emphasis, lists and links. Other elements are reduced to their contents.
+use std::slice;
+
+/// Prefix of links relative to the website.
+const BASE_URL: &str = "https://adventofcode.com";
+
+/// A node of an HTML fragment.
+#[derive(Debug, PartialEq, Eq)]
+enum Node {
+    Text(String),
+    Element {
+        tag: String,
+        href: Option<String>,
+        children: Vec<Node>,
+    },
+}
+
+/// Converts an HTML fragment to Markdown.
+pub fn from_html(html: &str) -> String {
+    let mut out = String::new();
+    render_blocks(&parse(html), &mut out, 0);
+    out.trim().to_string()
+}
+
+/// Returns the text of an HTML fragment without any markup, keeping its whitespace.
+pub fn text(html: &str) -> String {
+    text_of(&parse(html))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses an HTML fragment into a tree, tolerating unclosed and stray tags.
+fn parse(html: &str) -> Vec<Node> {
+    // NOTE: the root and the open elements, innermost last.
+    let mut stack: Vec<(String, Option<String>, Vec<Node>)> = vec![(String::new(), None, vec![])];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+
+        push_text(&mut stack, &rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_lowercase();
+
+            // NOTE: close the innermost matching element, and any element left open within it.
+            if let Some(position) = stack.iter().rposition(|(x, _, _)| *x == name)
+                && position > 0
+            {
+                while stack.len() > position {
+                    close(&mut stack);
+                }
+            }
+
+            continue;
+        }
+
+        let name: String = tag
+            .trim_end_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        if ["br", "hr", "img", "input"].contains(&name.as_str()) || tag.ends_with('/') {
+            push_node(
+                &mut stack,
+                Node::Element {
+                    tag: name,
+                    href: None,
+                    children: vec![],
+                },
+            );
+        } else {
+            let href = attribute(tag, "href");
+            stack.push((name, href, vec![]));
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+
+    stack
+        .pop()
+        .map(|(_, _, children)| children)
+        .unwrap_or_default()
+}
+
+fn push_text(stack: &mut [(String, Option<String>, Vec<Node>)], text: &str) {
+    if !text.is_empty() {
+        push_node(stack, Node::Text(decode_entities(text)));
+    }
+}
+
+fn push_node(stack: &mut [(String, Option<String>, Vec<Node>)], node: Node) {
+    if let Some((_, _, children)) = stack.last_mut() {
+        children.push(node);
+    }
+}
+
+fn close(stack: &mut Vec<(String, Option<String>, Vec<Node>)>) {
+    if let Some((tag, href, children)) = stack.pop() {
+        push_node(
+            stack,
+            Node::Element {
+                tag,
+                href,
+                children,
+            },
+        );
+    }
+}
+
+/// Reads the value of an attribute of a tag, e.g. `href` of `a href="/2025"`.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(decode_entities(&tag[start..end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders nodes as blocks separated by blank lines, `depth` being the nesting of lists.
+fn render_blocks(nodes: &[Node], out: &mut String, depth: usize) {
+    let mut inline = String::new();
+
+    for node in nodes {
+        match node {
+            Node::Element { tag, children, .. } if is_block(tag) => {
+                flush_paragraph(&mut inline, out);
+
+                match tag.as_str() {
+                    "h1" | "h2" | "h3" => {
+                        let level = tag[1..].parse().unwrap_or(2);
+                        let title = render_inline(children);
+                        out.push_str(&format!("{} {}\n\n", "#".repeat(level), title.trim()));
+                    }
+                    "p" => {
+                        let text = render_inline(children);
+                        out.push_str(&format!("{}\n\n", text.trim()));
+                    }
+                    "pre" => {
+                        let code = text_of(children);
+                        out.push_str(&format!("```\n{}\n```\n\n", code.trim_end_matches('\n')));
+                    }
+                    "ul" | "ol" => {
+                        render_list(children, tag == "ol", out, depth);
+                        out.push('\n');
+                    }
+                    _ => render_blocks(children, out, depth),
+                }
+            }
+            node => inline.push_str(&render_inline(slice::from_ref(node))),
+        }
+    }
+
+    flush_paragraph(&mut inline, out);
+}
+
+fn render_list(items: &[Node], is_ordered: bool, out: &mut String, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let mut number = 0;
+
+    for item in items {
+        let Node::Element { tag, children, .. } = item else {
+            continue;
+        };
+
+        if tag != "li" {
+            continue;
+        }
+
+        number += 1;
+        let marker = if is_ordered {
+            format!("{number}.")
+        } else {
+            "-".into()
+        };
+
+        // NOTE: nested lists go on their own lines below the text of the item.
+        let (lists, inline): (Vec<&Node>, Vec<&Node>) = children
+            .iter()
+            .partition(|x| matches!(x, Node::Element { tag, .. } if tag == "ul" || tag == "ol"));
+
+        let text: String = inline
+            .iter()
+            .map(|x| render_inline(slice::from_ref(*x)))
+            .collect();
+
+        out.push_str(&format!("{indent}{marker} {}\n", collapse(&text).trim()));
+
+        for list in lists {
+            if let Node::Element { tag, children, .. } = list {
+                render_list(children, tag == "ol", out, depth + 1);
+            }
+        }
+    }
+}
+
+/// Renders nodes as text of a single block.
+fn render_inline(nodes: &[Node]) -> String {
+    let mut out = String::new();
+
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(&escape(&collapse(text))),
+            Node::Element {
+                tag,
+                href,
+                children,
+            } => match tag.as_str() {
+                "em" | "i" | "b" | "strong" => {
+                    let text = render_inline(children);
+                    out.push_str(&wrap_emphasis(&text));
+                }
+                "code" => out.push_str(&render_code(children)),
+                "a" => {
+                    let text = render_inline(children);
+                    match href {
+                        Some(href) => out.push_str(&format!("[{text}]({})", absolute(href))),
+                        None => out.push_str(&text),
+                    }
+                }
+                "br" => out.push_str("  \n"),
+                _ => out.push_str(&render_inline(children)),
+            },
+        }
+    }
+
+    out
+}
+
+/// Inline code can't contain emphasis, so code that is emphasized as a whole is emphasized from the outside.
+fn render_code(children: &[Node]) -> String {
+    let code = text_of(children);
+    let ticks = if code.contains('`') { "``" } else { "`" };
+
+    match children {
+        [Node::Element { tag, .. }] if tag == "em" => format!("*{ticks}{code}{ticks}*"),
+        _ => format!("{ticks}{code}{ticks}"),
+    }
+}
+
+/// Emphasis markers must hug the text, so surrounding whitespace is moved out of them.
+fn wrap_emphasis(text: &str) -> String {
+    let trimmed = text.trim();
+
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+    format!("{leading}*{trimmed}*{trailing}")
+}
+
+/// The text of nodes without any markup, as used in code.
+fn text_of(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => text.clone(),
+            Node::Element { children, .. } => text_of(children),
+        })
+        .collect()
+}
+
+fn flush_paragraph(inline: &mut String, out: &mut String) {
+    let text = inline.trim();
+
+    if !text.is_empty() {
+        out.push_str(text);
+        out.push_str("\n\n");
+    }
+
+    inline.clear();
+}
+
+fn is_block(tag: &str) -> bool {
+    ["h1", "h2", "h3", "p", "pre", "ul", "ol", "div", "article"].contains(&tag)
+}
+
+/// Collapses runs of whitespace, as HTML does outside of `<pre>`.
+fn collapse(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut is_space = false;
+
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !is_space {
+                out.push(' ');
+            }
+            is_space = true;
+        } else {
+            out.push(c);
+            is_space = false;
+        }
+    }
+
+    out
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if ['\\', '*', '_', '`', '[', ']'].contains(&c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+
+    out
+}
+
+fn absolute(href: &str) -> String {
+    if href.starts_with('/') {
+        format!("{BASE_URL}{href}")
+    } else {
+        href.to_string()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::from_html;
+
+    #[test]
+    fn converts_headings_and_paragraphs() {
+        assert_eq!(
+            from_html(
+                "<h2>--- Day 1: Trebuchet?! ---</h2><p>Something is\n  wrong.</p><p>Fix it.</p>"
+            ),
+            "## --- Day 1: Trebuchet?! ---\n\nSomething is wrong.\n\nFix it."
+        );
+    }
+
+    #[test]
+    fn converts_emphasis_and_inline_code() {
+        assert_eq!(
+            from_html(
+                "<p>The sum is <code>142</code>, or <em>in total</em> <code><em>281</em></code>.</p>"
+            ),
+            "The sum is `142`, or *in total* *`281`*."
+        );
+        assert_eq!(
+            from_html("<p>Count<em> stars </em>now.</p>"),
+            "Count *stars* now."
+        );
+    }
+
+    #[test]
+    fn converts_code_blocks() {
+        assert_eq!(
+            from_html(
+                "<p>For example:</p>\n<pre><code>1abc2\n<em>pqr</em>3stu8vwx\n</code></pre>\n"
+            ),
+            "For example:\n\n```\n1abc2\npqr3stu8vwx\n```"
+        );
+    }
+
+    #[test]
+    fn converts_lists() {
+        assert_eq!(
+            from_html(
+                "<ul>\n<li>In <code>1abc2</code>, it is <code>12</code>.</li>\n<li>Nested:<ul><li>one</li></ul></li>\n</ul>"
+            ),
+            "- In `1abc2`, it is `12`.\n- Nested:\n  - one"
+        );
+        assert_eq!(
+            from_html("<ol><li>first</li><li>second</li></ol>"),
+            "1. first\n2. second"
+        );
+    }
+
+    #[test]
+    fn converts_links() {
+        assert_eq!(
+            from_html(
+                "<p>Get <a href=\"/2025/day/1/input\" target=\"_blank\">your input</a> or <a href=\"https://example.com/?a=1&amp;b=2\">read</a>.</p>"
+            ),
+            "Get [your input](https://adventofcode.com/2025/day/1/input) or [read](https://example.com/?a=1&b=2)."
+        );
+    }
+
+    #[test]
+    fn escapes_text_and_keeps_unknown_elements() {
+        assert_eq!(
+            from_html(
+                "<p>Use <span title=\"hidden\">a*b</span> &amp; [c]<!-- note --></p><p>Unclosed <em>tag"
+            ),
+            "Use a\\*b & \\[c\\]\n\nUnclosed *tag*"
+        );
+    }
+}
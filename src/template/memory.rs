@@ -0,0 +1,176 @@
+/// Lightweight heap accounting, an always-available alternative to `--dhat`.
+/// A counting allocator wraps the system allocator. It only does bookkeeping while a measurement is underway,
+/// so that benchmarks are not affected.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering},
+};
+
+/// Whether the counting allocator is the global allocator, `dhat` installs its own.
+const IS_INSTALLED: bool = cfg!(not(feature = "dhat-heap"));
+
+#[cfg(not(feature = "dhat-heap"))]
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+// NOTE: installed by the library rather than by `solution!`, so that the main binary has a single
+// global allocator when the `registry` feature links every solution into it.
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static DHAT_ALLOC: dhat::Alloc = dhat::Alloc;
+
+static IS_MEASURING: AtomicBool = AtomicBool::new(false);
+
+/// Live heap bytes relative to the start of the measurement.
+/// NOTE: it can be negative, as memory allocated beforehand may be freed during the measurement.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Heap usage of a measured function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Allocations {
+    /// Highest amount of heap memory held at once, on top of what was held before.
+    pub peak_bytes: usize,
+    /// Number of allocations, including reallocations.
+    pub count: usize,
+}
+
+/// Global allocator that counts allocations and tracks peak heap usage while a measurement is underway.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: forwarded as is, the caller upholds the contract of `GlobalAlloc`.
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            track(layout.size().cast_signed());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: forwarded as is, the caller upholds the contract of `GlobalAlloc`.
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            track(layout.size().cast_signed());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: forwarded as is, the caller upholds the contract of `GlobalAlloc`.
+        unsafe { System.dealloc(ptr, layout) };
+        untrack(layout.size().cast_signed());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // SAFETY: forwarded as is, the caller upholds the contract of `GlobalAlloc`.
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            track(new_size.cast_signed() - layout.size().cast_signed());
+        }
+        new_ptr
+    }
+}
+
+fn track(delta: isize) {
+    if IS_MEASURING.load(Ordering::Relaxed) {
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(delta, Ordering::Relaxed) + delta;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+fn untrack(size: isize) {
+    if IS_MEASURING.load(Ordering::Relaxed) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+/// Run a function and measure its heap usage.
+/// Returns [`None`] for the usage if the counting allocator is not installed, i.e. with `dhat-heap`.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    if !IS_INSTALLED {
+        return (func(), None);
+    }
+
+    CURRENT.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+    COUNT.store(0, Ordering::Relaxed);
+
+    IS_MEASURING.store(true, Ordering::SeqCst);
+    let result = func();
+    IS_MEASURING.store(false, Ordering::SeqCst);
+
+    let allocations = Allocations {
+        peak_bytes: PEAK.load(Ordering::Relaxed).unsigned_abs(),
+        count: COUNT.load(Ordering::Relaxed),
+    };
+
+    (result, Some(allocations))
+}
+
+/// Formats an amount of bytes with binary prefixes, e.g. `1.5 MiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} peak · {} allocs",
+            format_bytes(self.peak_bytes),
+            self.count
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::hint::black_box;
+
+    use super::{format_bytes, measure};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn measures_allocations() {
+        let (len, allocations) = measure(|| {
+            let small = black_box(vec![0_u8; 1024]);
+            let large = black_box(vec![0_u8; 4096]);
+            small.len() + large.len()
+        });
+
+        let allocations = allocations.unwrap();
+        assert_eq!(len, 5120);
+        assert!(allocations.count >= 2);
+        assert!(allocations.peak_bytes >= 5120);
+    }
+}
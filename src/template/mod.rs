@@ -1,15 +1,36 @@
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod answer;
+pub mod aoc_client;
+pub mod cli;
 pub mod commands;
+pub mod config;
+pub mod profile;
+pub mod registry;
 pub mod runner;
 
 pub use day::*;
+pub use day_set::*;
+pub use run_multi::Limits;
+pub use year::*;
 
+mod answers;
 mod day;
+mod day_set;
+mod examples;
+mod leaderboard;
+mod manifest;
+mod markdown;
+mod memory;
+mod ocr;
 mod readme_benchmarks;
+mod readme_stars;
+mod report;
 mod run_multi;
+mod stats;
+mod submissions;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -18,20 +39,38 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 /// Helper function that reads a text file to a string.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    read_data_file(Year::current(), folder, &format!("{day}.txt"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+    read_data_file(Year::current(), folder, &format!("{day}-{part}.txt"))
+}
+
+/// Like [`read_file`], for solutions of another year than the default one, e.g. in their tests.
+#[must_use]
+pub fn read_year_file(folder: &str, puzzle: PuzzleId) -> String {
+    read_data_file(
+        Some(puzzle.year()),
+        folder,
+        &format!("{}.txt", puzzle.day()),
+    )
+}
+
+/// Like [`read_file_part`], for solutions of another year than the default one, e.g. in their tests.
+#[must_use]
+pub fn read_year_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
+    read_data_file(
+        Some(puzzle.year()),
+        folder,
+        &format!("{}-{part}.txt", puzzle.day()),
+    )
+}
+
+fn read_data_file(year: Option<Year>, folder: &str, name: &str) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
+    let filepath = cwd.join(profile::data_dir_in(year, folder)).join(name);
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
@@ -39,30 +78,79 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The optional `parse = <function>` parameter runs a shared parse step once, timed on its own.
+/// Its output is then handed to both parts by reference.
+///
+/// Solutions of another year than the default one start with a `year = <year>` parameter,
+/// which also creates the constant `PUZZLE`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    (year = $year:expr, $($rest:tt)+) => {
+        $crate::solution!(@solution [$year] $($rest)+);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+
+    (@solution [$($year:expr)?] $day:expr) => {
+        $crate::solution!(@impl [$($year)?] $day, [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    (@solution [$($year:expr)?] $day:expr, 1) => {
+        $crate::solution!(@impl [$($year)?] $day, [part_one, 1]);
+    };
+    (@solution [$($year:expr)?] $day:expr, 2) => {
+        $crate::solution!(@impl [$($year)?] $day, [part_two, 2]);
+    };
+    (@solution [$($year:expr)?] $day:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl [$($year)?] $day, parse = $parse, [part_one, 1] [part_two, 2]);
+    };
+    (@solution [$($year:expr)?] $day:expr, 1, parse = $parse:expr) => {
+        $crate::solution!(@impl [$($year)?] $day, parse = $parse, [part_one, 1]);
+    };
+    (@solution [$($year:expr)?] $day:expr, 2, parse = $parse:expr) => {
+        $crate::solution!(@impl [$($year)?] $day, parse = $parse, [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
-
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+    (@impl [$($year:expr)?] $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@header [$($year)?] $day);
 
-        fn main() {
+        /// Runs the solution against its input, both from `main` and from the in-process registry.
+        pub fn run_solution() {
             use $crate::template::runner::*;
             let input = $crate::template::read_file("inputs", DAY);
             $( run_part($func, &input, DAY, $part); )*
         }
     };
+    (@impl [$($year:expr)?] $day:expr, parse = $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@header [$($year)?] $day);
+
+        /// Runs the solution against its input, both from `main` and from the in-process registry.
+        pub fn run_solution() {
+            use $crate::template::runner::*;
+            let input = $crate::template::read_file("inputs", DAY);
+            let parsed = run_parse($parse, input.as_str());
+            $( run_part(|input| $func(input), &parsed, DAY, $part); )*
+        }
+    };
+
+    (@header [$($year:expr)?] $day:expr) => {
+        /// The current day.
+        const DAY: $crate::template::Day = $crate::day!($($year,)? $day);
+
+        $(
+            /// The puzzle of the current day, for solutions of another year than the default one.
+            /// Scaffolded tests read their examples with it.
+            #[allow(dead_code)]
+            const PUZZLE: $crate::template::PuzzleId =
+                $crate::template::PuzzleId::new($crate::year!($year), DAY).unwrap();
+        )?
+
+        fn main() {
+            $( $crate::template::Year::select($crate::year!($year)); )?
+            $crate::template::runner::limit_memory();
+            run_solution();
+        }
+    };
+
+    ($($rest:tt)+) => {
+        $crate::solution!(@solution [] $($rest)+);
+    };
 }
@@ -0,0 +1,278 @@
+/// Reads the block letters that Advent of Code answers are sometimes drawn with.
+/// Both fonts used by the puzzles are supported: letters 6 cells high (mostly 4 wide) and letters 10 cells high (6 wide).
+/// Letters are told apart by the blank columns between them.
+use crate::template::answer::Grid;
+
+/// Letters 6 cells high, as drawn by most puzzles.
+const SMALL_FONT: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters 10 cells high, as drawn by e.g. 2018 day 10.
+const LARGE_FONT: [(char, [&str; 10]); 15] = [
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+/// Cells of a letter, row by row.
+type Glyph = Vec<Vec<bool>>;
+
+/// Reads the letters drawn on a grid, returns [`None`] if the grid is empty or any letter is unknown.
+pub fn read(grid: &Grid) -> Option<String> {
+    let rows = trim_rows(grid.rows());
+
+    let font: Vec<(char, Glyph)> = match rows.len() {
+        6 => SMALL_FONT
+            .iter()
+            .map(|(c, rows)| (*c, parse(rows)))
+            .collect(),
+        10 => LARGE_FONT
+            .iter()
+            .map(|(c, rows)| (*c, parse(rows)))
+            .collect(),
+        _ => return None,
+    };
+
+    split(rows)
+        .iter()
+        .map(|glyph| {
+            font.iter()
+                .find(|(_, letter)| letter == glyph)
+                .map(|(c, _)| *c)
+        })
+        .collect()
+}
+
+/// Removes the blank rows above and below the drawing.
+fn trim_rows(rows: &[Vec<bool>]) -> &[Vec<bool>] {
+    let is_lit = |row: &Vec<bool>| row.contains(&true);
+
+    match (rows.iter().position(is_lit), rows.iter().rposition(is_lit)) {
+        (Some(start), Some(end)) => &rows[start..=end],
+        _ => &[],
+    }
+}
+
+/// Splits a drawing into letters at blank columns, leaving out the blank columns.
+fn split(rows: &[Vec<bool>]) -> Vec<Glyph> {
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let cell = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+
+    let mut glyphs = vec![];
+    let mut start = None;
+
+    for x in 0..=width {
+        let is_blank = x == width || (0..rows.len()).all(|y| !cell(x, y));
+
+        match (start, is_blank) {
+            (None, false) => start = Some(x),
+            (Some(from), true) => {
+                glyphs.push(
+                    (0..rows.len())
+                        .map(|y| (from..x).map(|x| cell(x, y)).collect())
+                        .collect(),
+                );
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    glyphs
+}
+
+/// Parses the rows of a letter of a font, trimmed like the letters of a drawing.
+fn parse(rows: &[&str]) -> Glyph {
+    let rows: Vec<Vec<bool>> = rows
+        .iter()
+        .map(|row| row.chars().map(|c| c == '#').collect())
+        .collect();
+
+    split(&rows).remove(0)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{LARGE_FONT, SMALL_FONT, read};
+    use crate::template::answer::Grid;
+
+    /// Draws letters of a font side by side, separated by `spacing` blank columns.
+    fn draw<const N: usize>(font: &[(char, [&str; N])], text: &str, spacing: usize) -> Grid {
+        let glyphs: Vec<&[&str; N]> = text
+            .chars()
+            .map(|c| &font.iter().find(|(x, _)| *x == c).unwrap().1)
+            .collect();
+
+        let rows: Vec<String> = (0..N)
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|glyph| glyph[y])
+                    .collect::<Vec<_>>()
+                    .join(&".".repeat(spacing))
+            })
+            .collect();
+
+        Grid::from(rows.join("\n").as_str())
+    }
+
+    #[test]
+    fn reads_small_letters() {
+        let text: String = SMALL_FONT.iter().map(|(c, _)| c).collect();
+        assert_eq!(read(&draw(&SMALL_FONT, &text, 1)), Some(text));
+    }
+
+    #[test]
+    fn reads_large_letters() {
+        let text: String = LARGE_FONT.iter().map(|(c, _)| c).collect();
+        assert_eq!(read(&draw(&LARGE_FONT, &text, 2)), Some(text));
+    }
+
+    #[test]
+    fn ignores_surrounding_blank_cells() {
+        let grid = Grid::from(
+            [
+                "..........",
+                "...####...",
+                "...#......",
+                "...###....",
+                "...#......",
+                "...#......",
+                "...####...",
+                "..........",
+            ]
+            .join("\n")
+            .as_str(),
+        );
+
+        assert_eq!(read(&grid), Some("E".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_letters() {
+        assert_eq!(read(&Grid::from("#..#\n.##.\n#..#")), None);
+        assert_eq!(read(&Grid::from("....\n....")), None);
+    }
+}
@@ -0,0 +1,176 @@
+/// Session profiles, to solve with several Advent of Code accounts in one repository.
+/// A profile is a directory `~/.config/adventofcode/profiles/<name>`, holding the session cookie of
+/// the account in `session`, and optionally the data root of the account in `data_root`, e.g. `data/alt`.
+/// Without one, the data of the account is kept in `profiles/<name>` of the data root of `aoc.toml`.
+/// The active profile is named by `AOC_PROFILE`, which `--profile <name>` sets.
+/// Within a data root, the files of other years than the default one are kept in a folder per year.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::template::{Year, config};
+
+/// Folders that are the same for every account, and are always kept in the data root of `aoc.toml`.
+const SHARED_FOLDERS: [&str; 1] = ["examples"];
+
+/// Activates a profile for this process and the solutions it runs.
+pub fn activate(name: &str) -> Result<(), String> {
+    check_name(name)?;
+
+    let dir = get_dir(name).ok_or("could not find the home directory.")?;
+    if !dir.is_dir() {
+        return Err(format!(
+            "profile `{name}` does not exist, create it at \"{}\".",
+            dir.display()
+        ));
+    }
+
+    // SAFETY: profiles are activated while parsing arguments, before any other thread is started.
+    unsafe { env::set_var("AOC_PROFILE", name) };
+    Ok(())
+}
+
+/// Returns the name of the active profile, if one is.
+/// Fails if `AOC_PROFILE` is not a valid name, as it would point outside of the profiles.
+pub fn active() -> Result<Option<String>, String> {
+    match env::var("AOC_PROFILE").ok().filter(|name| !name.is_empty()) {
+        Some(name) => check_name(&name).map(|()| Some(name)),
+        None => Ok(None),
+    }
+}
+
+/// Rejects names that are not a single folder, e.g. `../alt`.
+fn check_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+        return Err(format!("`{name}` is not a valid profile name."));
+    }
+
+    Ok(())
+}
+
+/// Returns the directory of a profile, whether or not it exists.
+#[must_use]
+pub fn get_dir(name: &str) -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(
+        PathBuf::from(home)
+            .join(".config")
+            .join("adventofcode")
+            .join("profiles")
+            .join(name),
+    )
+}
+
+/// Returns the path of the session cookie of a profile.
+#[must_use]
+pub fn get_session_path(name: &str) -> Option<PathBuf> {
+    get_dir(name).map(|dir| dir.join("session"))
+}
+
+/// Returns the data root of the active profile, relative to the repository.
+/// Without a profile, it is the data root of `aoc.toml`. Exits if the profile is invalid.
+#[must_use]
+pub fn data_root() -> PathBuf {
+    match active() {
+        Ok(Some(name)) => profile_root(&name, get_dir(&name).as_deref()),
+        Ok(None) => config::get().data_root.clone(),
+        Err(e) => {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Returns the data root named in the `data_root` file of a profile, or else `data/profiles/<name>`,
+/// so that accounts never share inputs, answers or submissions.
+fn profile_root(name: &str, dir: Option<&Path>) -> PathBuf {
+    dir.and_then(read_data_root)
+        .unwrap_or_else(|| config::get().data_root.join("profiles").join(name))
+}
+
+/// Returns the path of a data folder of the current year, e.g. `data/inputs` or `data/2024/inputs`,
+/// in the data root of the active profile.
+#[must_use]
+pub fn data_dir(folder: &str) -> PathBuf {
+    data_dir_in(Year::current(), folder)
+}
+
+/// Returns the path of a data folder of a year, in the data root of the active profile.
+#[must_use]
+pub fn data_dir_in(year: Option<Year>, folder: &str) -> PathBuf {
+    let root = if SHARED_FOLDERS.contains(&folder) {
+        config::get().data_root.clone()
+    } else {
+        data_root()
+    };
+
+    year_dir(root, year).join(folder)
+}
+
+/// Returns the directory of the files of the current year that are shared by all profiles,
+/// e.g. `data` or `data/2024`.
+#[must_use]
+pub fn shared_dir() -> PathBuf {
+    year_dir(config::get().data_root.clone(), Year::current())
+}
+
+fn year_dir(root: PathBuf, year: Option<Year>) -> PathBuf {
+    match year.filter(|year| !year.is_default()) {
+        Some(year) => root.join(year.to_string()),
+        None => root,
+    }
+}
+
+fn read_data_root(dir: &Path) -> Option<PathBuf> {
+    let root = fs::read_to_string(dir.join("data_root")).ok()?;
+    Some(root.trim())
+        .filter(|root| !root.is_empty())
+        .map(PathBuf::from)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_name, data_dir, profile_root, read_data_root};
+    use std::{env, fs, path::PathBuf};
+
+    #[test]
+    fn keeps_examples_shared() {
+        assert_eq!(data_dir("examples"), PathBuf::from("data/examples"));
+    }
+
+    #[test]
+    fn validates_names() {
+        assert!(check_name("alt").is_ok());
+        assert!(check_name("../x").is_err());
+        assert!(check_name("a/b").is_err());
+        assert!(check_name(".hidden").is_err());
+        assert!(check_name("").is_err());
+    }
+
+    #[test]
+    fn reads_data_roots() {
+        let dir = env::temp_dir().join(format!("aoc-profile-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        assert_eq!(read_data_root(&dir), None);
+
+        fs::write(dir.join("data_root"), "data/alt\n").unwrap();
+        assert_eq!(read_data_root(&dir), Some(PathBuf::from("data/alt")));
+
+        fs::write(dir.join("data_root"), " \n").unwrap();
+        assert_eq!(read_data_root(&dir), None);
+        assert_eq!(
+            profile_root("alt", Some(&dir)),
+            PathBuf::from("data/profiles/alt")
+        );
+        assert_eq!(
+            profile_root("alt", None),
+            PathBuf::from("data/profiles/alt")
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -2,97 +2,178 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::memory;
+use crate::template::report::Step;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{PuzzleId, Year, config, get_bin_path};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     Parser(String),
     IO(io::Error),
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => f.write_str(e),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
     }
 }
 
-pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+pub(crate) struct TablePosition {
+    pub(crate) pos_start: usize,
+    pub(crate) pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Returns the marker of the table of a year, e.g. `<!--- benchmarking table 2024 --->`.
+/// The table of the default year keeps the marker without a year.
+pub(crate) fn get_marker(marker: &str, year: Option<Year>) -> String {
+    match year.filter(|year| !year.is_default()) {
+        Some(year) => marker.replace(" --->", &format!(" {year} --->")),
+        None => marker.to_string(),
+    }
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locates the table between a pair of markers, which the stars table shares.
+pub(crate) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "too many occurences of `{marker}` in README."
+        )));
     }
 
     let pos_start = matches
         .first()
         .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+        .ok_or_else(|| Error::Parser(format!("Could not find `{marker}` in README.")))?;
 
     let pos_end = matches
         .last()
         .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+        .ok_or_else(|| Error::Parser(format!("Could not find the end of `{marker}` in README.")))?;
 
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    prefix: &str,
+    marker: &str,
+    year: Option<Year>,
+    timings: Timings,
+    total_millis: f64,
+) -> String {
+    let header = match year.filter(|year| !year.is_default()) {
+        Some(year) => format!("{prefix} {year} Benchmarks"),
+        None => format!("{prefix} Benchmarks"),
+    };
+
+    // NOTE: only show the parse column if at least one solution has a parse step.
+    let has_parse = timings.data.iter().any(|timing| timing.parse.is_some());
+    // NOTE: likewise, the memory column is only shown if heap usage was measured with `--memory`.
+    let has_memory = timings
+        .data
+        .iter()
+        .any(|timing| timing.peak_bytes().is_some());
+
+    let mut lines: Vec<String> = vec![marker.into(), header, String::new()];
+
+    let (mut columns, mut alignments) = if has_parse {
+        (
+            "| Day | Parse | Part 1 | Part 2 |".to_string(),
+            "| :---: | :---: | :---: | :---:  |".to_string(),
+        )
+    } else {
+        (
+            "| Day | Part 1 | Part 2 |".to_string(),
+            "| :---: | :---: | :---:  |".to_string(),
+        )
+    };
+
+    if has_memory {
+        columns.push_str(" Peak memory |");
+        alignments.push_str(" :---: |");
+    }
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    lines.push(columns);
+    lines.push(alignments);
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = year
+            .and_then(|year| PuzzleId::new(year, timing.day))
+            .map_or_else(|| get_bin_path(timing.day), PuzzleId::bin_path);
+        let parse = if has_parse {
+            format!(" `{}` |", format_cell(&timing, Step::Parse, &timing.parse))
+        } else {
+            String::new()
+        };
+
+        let memory = if has_memory {
+            let peak_bytes = timing
+                .peak_bytes()
+                .map_or_else(|| "-".into(), memory::format_bytes);
+            format!(" `{peak_bytes}` |")
+        } else {
+            String::new()
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) |{} `{}` | `{}` |{}",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            parse,
+            format_cell(&timing, Step::Part(1), &timing.part_1),
+            format_cell(&timing, Step::Part(2), &timing.part_2),
+            memory
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+/// A step is shown with its duration, how it failed, or a dash if it didn't run.
+fn format_cell(timing: &Timing, step: Step, duration: &Option<String>) -> String {
+    match (duration, timing.failure(step)) {
+        (Some(duration), _) => duration.clone(),
+        (None, Some(status)) => status.to_string(),
+        (None, None) => "-".into(),
+    }
+}
+
+fn update_content(
+    s: &mut String,
+    year: Option<Year>,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let marker = get_marker(MARKER, year);
+    let positions = locate_table(s, &marker)?;
+    let table = construct_table("##", &marker, year, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
+/// Updates the benchmarks table of the current year.
 pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
+    let path = &config::get().readme;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, Year::current(), timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -100,28 +181,31 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
+    use crate::template::memory::Allocations;
+    use crate::template::report::{Status, Step};
+    use crate::year;
     use crate::{day, template::timings::Timing, template::timings::Timings};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -131,28 +215,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +244,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -180,4 +264,79 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_parse() {
+        let mut timings = get_mock_timings();
+        timings.data[0].parse = Some("5ms".into());
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, None, timings, 195.0).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/01.rs) | `5ms` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/02.rs) | `-` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/04.rs) | `-` | `40ms` | `50ms` |",
+            "",
+            "**Total: 195.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_benchmarks_with_failures() {
+        let mut timings = get_mock_timings();
+        timings.data[1].part_2 = None;
+        timings.data[1].failures = vec![(Step::Part(2), Status::TimedOut)];
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, None, timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains("| [Day 2](./src/bin/02.rs) | `30ms` | `timed out` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_with_memory() {
+        let mut timings = get_mock_timings();
+        timings.data[0].allocations = vec![(
+            Step::Part(1),
+            Allocations {
+                peak_bytes: 3 * 1024 * 1024,
+                count: 10,
+            },
+        )];
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, None, timings, 190.0).unwrap();
+        assert_eq!(s.contains("| Day | Part 1 | Part 2 | Peak memory |"), true);
+        assert_eq!(
+            s.contains("| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` | `3.0 MiB` |"),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` | `-` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn keeps_a_table_per_year() {
+        let mut s = format!(
+            "{}\n{}\n<!--- benchmarking table 2024 --->\n<!--- benchmarking table 2024 --->",
+            MARKER, MARKER
+        );
+        update_content(&mut s, Some(year!(2024)), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.starts_with(&format!("{}\n{}\n", MARKER, MARKER)), true);
+        assert_eq!(s.contains("## 2024 Benchmarks"), true);
+        assert_eq!(s.contains("| [Day 1](./src/bin/2024_01.rs) |"), true);
+        assert_eq!(s.ends_with("<!--- benchmarking table 2024 --->"), true);
+    }
 }
@@ -0,0 +1,218 @@
+/// Module that updates the readme with the stars collected so far.
+/// The table is replaced between markers, located like the benchmarks table of `readme_benchmarks`.
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::template::leaderboard::Member;
+use crate::template::readme_benchmarks::{Error, get_marker, locate_table};
+use crate::template::{Day, Year, answers, config};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// The parts solved of every day, as far as they are known.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    days: BTreeMap<Day, [bool; 2]>,
+}
+
+impl Progress {
+    /// Marks a part of a day as solved.
+    pub fn insert(&mut self, day: Day, part: u8) {
+        let parts = self.days.entry(day).or_default();
+        parts[usize::from(part - 1)] = true;
+    }
+
+    /// Marks every part solved in another progress as solved in this one.
+    pub fn merge(&mut self, other: &Progress) {
+        for (day, parts) in &other.days {
+            for part in [1, 2] {
+                if parts[usize::from(part - 1)] {
+                    self.insert(*day, part);
+                }
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn stars(&self) -> usize {
+        self.days.values().flatten().filter(|x| **x).count()
+    }
+}
+
+/// Collects the parts of the event of a year whose accepted answer is stored in `data/answers`.
+#[must_use]
+pub fn local(year: Year) -> Progress {
+    let mut progress = Progress::default();
+
+    for day in year.all_days() {
+        for part in [1, 2] {
+            if answers::read(day, part).is_some() {
+                progress.insert(day, part);
+            }
+        }
+    }
+
+    progress
+}
+
+impl From<&Member> for Progress {
+    fn from(member: &Member) -> Self {
+        let mut progress = Progress::default();
+
+        for (day, stars) in &member.days {
+            if stars.part_1.is_some() {
+                progress.insert(*day, 1);
+            }
+            if stars.part_2.is_some() {
+                progress.insert(*day, 2);
+            }
+        }
+
+        progress
+    }
+}
+
+fn construct_table(prefix: &str, marker: &str, year: Year, progress: &Progress) -> String {
+    let mut lines: Vec<String> = vec![
+        marker.into(),
+        format!("{prefix} {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for (day, parts) in &progress.days {
+        let day = day.into_inner();
+        let [part_1, part_2] = parts.map(|solved| if solved { "⭐" } else { " " });
+        lines.push(format!(
+            "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {part_1} | {part_2} |"
+        ));
+    }
+
+    lines.push(marker.into());
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: Year, progress: &Progress) -> Result<(), Error> {
+    let marker = get_marker(MARKER, Some(year));
+    let positions = locate_table(s, &marker)?;
+    let table = construct_table("##", &marker, year, progress);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Updates the stars table of a year. Every year but the default one has its own table.
+pub fn update(year: Year, progress: &Progress) -> Result<(), Error> {
+    let path = &config::get().readme;
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, year, progress)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MARKER, Progress, update_content};
+    use crate::template::leaderboard::{DayStars, Member};
+    use crate::{day, year};
+
+    fn get_mock_progress() -> Progress {
+        let mut progress = Progress::default();
+        progress.insert(day!(1), 1);
+        progress.insert(day!(1), 2);
+        progress.insert(day!(3), 1);
+        progress
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, year!(2025), &get_mock_progress()).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_too_many_markers_present() {
+        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
+        update_content(&mut s, year!(2025), &get_mock_progress()).unwrap();
+    }
+
+    #[test]
+    fn updates_existing_stars() {
+        let mut s = format!("foo\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, year!(2025), &get_mock_progress()).unwrap();
+        update_content(&mut s, year!(2025), &get_mock_progress()).unwrap();
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches("## 2025 Results").count(), 1);
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\n| stale |\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, year!(2025), &get_mock_progress()).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2025/day/3) | ⭐ |   |",
+            "<!--- advent_readme_stars table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn merges_progress() {
+        let member = Member {
+            id: 1,
+            name: None,
+            local_score: 0,
+            stars: 2,
+            last_star_ts: 0,
+            days: vec![
+                (
+                    day!(3),
+                    DayStars {
+                        part_1: Some(10),
+                        part_2: Some(20),
+                    },
+                ),
+                (
+                    day!(4),
+                    DayStars {
+                        part_1: Some(30),
+                        part_2: None,
+                    },
+                ),
+            ],
+        };
+
+        let mut progress = get_mock_progress();
+        progress.merge(&Progress::from(&member));
+        assert_eq!(progress.stars(), 5);
+
+        let mut expected = get_mock_progress();
+        expected.insert(day!(3), 2);
+        expected.insert(day!(4), 1);
+        assert_eq!(progress, expected);
+    }
+
+    #[test]
+    fn keeps_a_table_per_year() {
+        let mut s = format!(
+            "{}{}\n<!--- advent_readme_stars table 2024 ---><!--- advent_readme_stars table 2024 --->",
+            MARKER, MARKER
+        );
+        update_content(&mut s, year!(2024), &get_mock_progress()).unwrap();
+        assert_eq!(s.starts_with(&format!("{}{}\n", MARKER, MARKER)), true);
+        assert_eq!(s.matches("## 2024 Results").count(), 1);
+    }
+}
@@ -0,0 +1,16 @@
+/// Solutions linked into the main binary with the `registry` feature, so they can be run in-process.
+/// The list of solutions is generated by the build script from the files in `src/bin`.
+use crate::template::Day;
+
+/// A solution that can be run in-process.
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub day: Day,
+    /// Runs every part of the solution, as its binary would.
+    pub run: fn(),
+}
+
+/// Finds the solution of a day in a registry.
+pub fn find(registry: &[Solution], day: Day) -> Option<&Solution> {
+    registry.iter().find(|solution| solution.day == day)
+}
@@ -0,0 +1,404 @@
+/// Machine-readable records exchanged between solution binaries and `run_multi`.
+/// Each record is a JSON object on its own line, prefixed with an ASCII record separator as in
+/// [RFC 7464](https://www.rfc-editor.org/rfc/rfc7464), so it can't be confused with other output.
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::answers::Check;
+use crate::template::memory::Allocations;
+use crate::template::stats::Stats;
+
+/// Leading character of every record line.
+const RECORD_SEPARATOR: char = '\x1e';
+
+/// The step of a solution a record reports on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(u8),
+}
+
+/// The outcome of a step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The step started, its first run is underway.
+    Running,
+    /// The first run of the step is done, it is being benched.
+    Benching,
+    Solved,
+    Unsolved,
+    TimedOut,
+    OutOfMemory,
+    Crashed,
+}
+
+impl Status {
+    /// Whether the status only reports progress, another record follows for the same step.
+    pub fn is_transient(self) -> bool {
+        matches!(self, Status::Running | Status::Benching)
+    }
+
+    /// Whether the step was interrupted before it could report a result.
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            Status::TimedOut | Status::OutOfMemory | Status::Crashed
+        )
+    }
+}
+
+/// The result of running a single step of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub step: Step,
+    pub answer: Option<String>,
+    pub nanos: f64,
+    pub samples: usize,
+    pub status: Status,
+    pub stats: Option<Stats>,
+    /// The verdict against the accepted answer, parts only.
+    pub check: Option<Check>,
+    /// Heap usage of the first run, if it was measured.
+    pub allocations: Option<Allocations>,
+}
+
+impl Record {
+    /// Creates a record of a step that has no result (yet).
+    pub fn without_result(step: Step, status: Status) -> Self {
+        Record {
+            step,
+            answer: None,
+            nanos: 0.0,
+            samples: 0,
+            status,
+            stats: None,
+            check: None,
+            allocations: None,
+        }
+    }
+
+    /// Serializes the record to a line of the protocol, without the trailing newline.
+    pub fn to_line(&self) -> String {
+        // NOTE: serializing a tree of objects, strings and finite numbers can't fail.
+        let json = JsonValue::from(self).stringify().unwrap();
+        format!("{RECORD_SEPARATOR}{json}")
+    }
+
+    /// Parses a line of output, returns [`None`] if it is not a record.
+    pub fn from_line(line: &str) -> Option<Result<Self, String>> {
+        let json = line.strip_prefix(RECORD_SEPARATOR)?;
+
+        Some(
+            JsonValue::from_str(json)
+                .map_err(|_| "record is not valid JSON.".to_string())
+                .and_then(|json| Record::try_from(&json)),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => f.write_str("Parse"),
+            Step::Part(part) => write!(f, "Part {part}"),
+        }
+    }
+}
+
+impl From<Step> for JsonValue {
+    fn from(value: Step) -> Self {
+        match value {
+            Step::Parse => JsonValue::String("parse".into()),
+            Step::Part(part) => JsonValue::Number(part.into()),
+        }
+    }
+}
+
+impl TryFrom<&JsonValue> for Step {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        match value {
+            JsonValue::String(s) if s == "parse" => Ok(Step::Parse),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            JsonValue::Number(part) => Ok(Step::Part(*part as u8)),
+            _ => Err("Expected part to be a number or `parse`.".into()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Status::Running => "running",
+            Status::Benching => "benching",
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::TimedOut => "timed out",
+            Status::OutOfMemory => "out of memory",
+            Status::Crashed => "crashed",
+        })
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "running" => Ok(Status::Running),
+            "benching" => Ok(Status::Benching),
+            "solved" => Ok(Status::Solved),
+            "unsolved" => Ok(Status::Unsolved),
+            "timed out" => Ok(Status::TimedOut),
+            "out of memory" => Ok(Status::OutOfMemory),
+            "crashed" => Ok(Status::Crashed),
+            _ => Err(format!("unknown status `{s}`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Record> for JsonValue {
+    fn from(value: &Record) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::from(value.step));
+
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        let (check, expected) = match &value.check {
+            None => (JsonValue::Null, JsonValue::Null),
+            Some(Check::Correct) => (JsonValue::String("correct".into()), JsonValue::Null),
+            Some(Check::Unknown) => (JsonValue::String("unknown".into()), JsonValue::Null),
+            Some(Check::Wrong { expected }) => (
+                JsonValue::String("wrong".into()),
+                JsonValue::String(expected.clone()),
+            ),
+        };
+
+        map.insert("check".into(), check);
+        map.insert("expected".into(), expected);
+
+        #[allow(clippy::cast_precision_loss)]
+        let (peak_bytes, allocations) = match value.allocations {
+            None => (JsonValue::Null, JsonValue::Null),
+            Some(allocations) => (
+                JsonValue::Number(allocations.peak_bytes as f64),
+                JsonValue::Number(allocations.count as f64),
+            ),
+        };
+
+        map.insert("peak_bytes".into(), peak_bytes);
+        map.insert("allocations".into(), allocations);
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Record {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let step = json
+            .get("part")
+            .ok_or_else(|| "Expected record.part to be a number or `parse`.".to_string())
+            .and_then(Step::try_from)?;
+
+        let answer = match json.get("answer") {
+            Some(JsonValue::String(s)) => Some(s.clone()),
+            Some(JsonValue::Null) => None,
+            _ => return Err("Expected record.answer to be null or string.".into()),
+        };
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.nanos to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.samples to be a number.")? as usize;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let stats = match json.get("stats") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(Stats::try_from(v)?),
+        };
+
+        let check = match (json.get("check"), json.get("expected")) {
+            (None | Some(JsonValue::Null), _) => None,
+            (Some(JsonValue::String(s)), _) if s == "correct" => Some(Check::Correct),
+            (Some(JsonValue::String(s)), _) if s == "unknown" => Some(Check::Unknown),
+            (Some(JsonValue::String(s)), Some(JsonValue::String(expected))) if s == "wrong" => {
+                Some(Check::Wrong {
+                    expected: expected.clone(),
+                })
+            }
+            _ => return Err("Expected record.check to be null or a known verdict.".into()),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let allocations = match (json.get("peak_bytes"), json.get("allocations")) {
+            (None | Some(JsonValue::Null), _) => None,
+            (Some(JsonValue::Number(peak_bytes)), Some(JsonValue::Number(count))) => {
+                Some(Allocations {
+                    peak_bytes: *peak_bytes as usize,
+                    count: *count as usize,
+                })
+            }
+            _ => {
+                return Err(
+                    "Expected record.peak_bytes and record.allocations to be numbers.".into(),
+                );
+            }
+        };
+
+        Ok(Record {
+            step,
+            answer,
+            nanos,
+            samples,
+            status,
+            stats,
+            check,
+            allocations,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Record, Status, Step};
+    use crate::template::{answers::Check, memory::Allocations, stats::Stats};
+
+    fn get_mock_record() -> Record {
+        Record {
+            step: Step::Part(1),
+            answer: Some("42".into()),
+            nanos: 1500.0,
+            samples: 10,
+            status: Status::Solved,
+            stats: Stats::from_samples(&[Duration::from_nanos(1500); 10]),
+            check: Some(Check::Correct),
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn roundtrips_records() {
+        let record = get_mock_record();
+        let parsed = Record::from_line(&record.to_line()).unwrap().unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn roundtrips_parse_records() {
+        let record = Record {
+            step: Step::Parse,
+            answer: None,
+            stats: None,
+            check: None,
+            ..get_mock_record()
+        };
+        let parsed = Record::from_line(&record.to_line()).unwrap().unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn roundtrips_multiline_answers() {
+        let record = Record {
+            answer: Some("#..#\n.##.\n\"x\"".into()),
+            ..get_mock_record()
+        };
+        let parsed = Record::from_line(&record.to_line()).unwrap().unwrap();
+        assert_eq!(parsed.answer, record.answer);
+    }
+
+    #[test]
+    fn roundtrips_wrong_answers() {
+        let record = Record {
+            check: Some(Check::Wrong {
+                expected: "41".into(),
+            }),
+            ..get_mock_record()
+        };
+        let parsed = Record::from_line(&record.to_line()).unwrap().unwrap();
+        assert_eq!(parsed.check, record.check);
+    }
+
+    #[test]
+    fn roundtrips_allocations() {
+        let record = Record {
+            allocations: Some(Allocations {
+                peak_bytes: 4096,
+                count: 12,
+            }),
+            ..get_mock_record()
+        };
+        let parsed = Record::from_line(&record.to_line()).unwrap().unwrap();
+        assert_eq!(parsed.allocations, record.allocations);
+    }
+
+    #[test]
+    fn roundtrips_failures() {
+        for status in [Status::Running, Status::TimedOut, Status::OutOfMemory] {
+            let record = Record::without_result(Step::Part(2), status);
+            let parsed = Record::from_line(&record.to_line()).unwrap().unwrap();
+            assert_eq!(parsed, record);
+        }
+    }
+
+    #[test]
+    fn ignores_other_lines() {
+        assert_eq!(Record::from_line("Part 1: 42 (1.0µs)"), None);
+        assert_eq!(Record::from_line(r#"{ "part": 1 }"#), None);
+    }
+
+    #[test]
+    fn errors_for_malformed_records() {
+        assert!(Record::from_line("\x1e{ \"part\": 1 }").unwrap().is_err());
+        assert!(Record::from_line("\x1enot json").unwrap().is_err());
+    }
+}
@@ -1,40 +1,185 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    io,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, get_bin_path};
 
 use super::{
     all_days,
+    answers::Check,
+    registry::{self, Solution},
+    report::{Record, Status, Step},
+    runner,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Tally of the verdicts of every part that was run against its accepted answer.
+#[derive(Debug, Default)]
+pub struct Summary {
+    pub correct: usize,
+    pub unknown: usize,
+    pub wrong: Vec<(Day, u8)>,
+    /// Steps that were interrupted, e.g. because they timed out.
+    pub failed: Vec<(Day, Step, Status)>,
+}
 
-    let mut need_space = false;
+impl Summary {
+    fn add(&mut self, day: Day, records: &[Record]) {
+        for record in records {
+            if record.status.is_failure() {
+                self.failed.push((day, record.step, record.status));
+                continue;
+            }
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+            let Step::Part(part) = record.step else {
+                continue;
+            };
+
+            match record.check {
+                Some(Check::Correct) => self.correct += 1,
+                Some(Check::Wrong { .. }) => self.wrong.push((day, part)),
+                Some(Check::Unknown) | None => self.unknown += 1,
             }
-            need_space = true;
+        }
+    }
+
+    /// Whether no part diverged from its accepted answer and every step ran to completion.
+    pub fn is_success(&self) -> bool {
+        self.wrong.is_empty() && self.failed.is_empty()
+    }
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    fn print(&self) {
+        println!(
+            "\n{ANSI_BOLD}Answers:{ANSI_RESET} {} correct ✔, {} wrong ✘, {} unknown ?, {} failed ✖",
+            self.correct,
+            self.wrong.len(),
+            self.unknown,
+            self.failed.len()
+        );
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        for (day, part) in &self.wrong {
+            println!(" ✘ Day {day}, part {part}");
+        }
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
+        for (day, step, status) in &self.failed {
+            println!(" ✖ Day {day}, {step}: {status}");
+        }
+    }
+}
+
+/// Limits enforced on every solution that runs in a child process.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Limits {
+    /// Wall-clock limit of the first run of every step. Benching is not limited, as it is bounded by the first run.
+    pub timeout: Option<Duration>,
+    /// Cap on the address space of every child in MiB.
+    pub memory_limit: Option<u64>,
+}
+
+/// Run the solutions of a set of days, either in child processes or in-process if a registry is given.
+/// With more than one job, child processes run concurrently and their output is buffered, then printed in day order.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    is_memory: bool,
+    jobs: usize,
+    limits: Limits,
+    registry: Option<&[Solution]>,
+) -> (Option<Timings>, Summary) {
+    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut summary = Summary::default();
+
+    if registry.is_some() {
+        runner::configure(runner::Options {
+            time: is_timed,
+            memory: is_memory,
+            ..runner::Options::default()
         });
 
-    if is_timed {
+        if jobs > 1 {
+            eprintln!("Warning: `--jobs` is ignored when running solutions in-process.");
+        }
+
+        if limits.timeout.is_some() || limits.memory_limit.is_some() {
+            eprintln!(
+                "Warning: `--timeout` and `--memory-limit` are ignored when running solutions in-process."
+            );
+        }
+    }
+
+    // NOTE: use non-duplicate, sorted day values.
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    let timer = Instant::now();
+    let mut need_space = false;
+
+    let mut print_header = |day: Day| {
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+    };
+
+    let mut finish_day = |day: Day, records: Vec<Record>| {
+        if records.is_empty() {
+            println!("Not solved.");
+        } else {
+            let val = child_commands::timing_from_records(&records, day);
+            timings.push(val);
+            summary.add(day, &records);
+        }
+    };
+
+    if jobs > 1 && registry.is_none() {
+        run_parallel(
+            &days,
+            is_release,
+            is_timed,
+            is_memory,
+            jobs,
+            limits,
+            |day, lines, records| {
+                print_header(day);
+                lines.iter().for_each(child_commands::Line::print);
+                finish_day(day, records);
+            },
+        );
+    } else {
+        for &day in &days {
+            print_header(day);
+
+            let records = match registry {
+                Some(registry) => registry::find(registry, day)
+                    .map(|solution| runner::collect(solution.run))
+                    .unwrap_or_default(),
+                None => {
+                    child_commands::run_solution(day, is_timed, is_memory, is_release, limits, None)
+                        .unwrap_or_else(|e| {
+                            eprintln!("Failed to run day {day}: {e:?}");
+                            failed_records()
+                        })
+                }
+            };
+
+            finish_day(day, records);
+        }
+    }
+
+    summary.print();
+
+    let timings = if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
@@ -42,8 +187,94 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         );
         Some(timings)
     } else {
+        let elapsed = timer.elapsed();
+        println!("\n{ANSI_BOLD}Total (Wall):{ANSI_RESET} {ANSI_ITALIC}{elapsed:.2?}{ANSI_RESET}");
         None
+    };
+
+    (timings, summary)
+}
+
+/// Run the solutions of `days` with `jobs` concurrent child processes.
+/// `on_day` is called with the buffered output of each day, in the order of `days`.
+fn run_parallel(
+    days: &[Day],
+    is_release: bool,
+    is_timed: bool,
+    is_memory: bool,
+    jobs: usize,
+    limits: Limits,
+    mut on_day: impl FnMut(Day, Vec<child_commands::Line>, Vec<Record>),
+) {
+    // NOTE: build every solution upfront, so that the children don't contend for cargo's build lock.
+    if let Err(e) = child_commands::build_solutions(is_release) {
+        eprintln!("Failed to build solutions: {e:?}");
     }
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let (tx, next) = (tx.clone(), &next);
+
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let buffer = Mutex::new(vec![]);
+                    let records = child_commands::run_solution(
+                        day,
+                        is_timed,
+                        is_memory,
+                        is_release,
+                        limits,
+                        Some(&buffer),
+                    );
+
+                    let mut lines = buffer.into_inner().unwrap();
+
+                    // NOTE: a day that could not run is reported as crashed, so that the days after it still print.
+                    let records = records.unwrap_or_else(|e| {
+                        lines.push(child_commands::Line::Stderr(format!(
+                            "Failed to run day {day}: {e:?}"
+                        )));
+                        failed_records()
+                    });
+
+                    if tx.send((day, lines, records)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(tx);
+
+        // NOTE: days may finish out of order, hold them back until every previous day is printed.
+        let mut pending = BTreeMap::new();
+        let mut remaining = days.iter();
+        let mut expected = remaining.next();
+
+        for (day, lines, records) in rx {
+            pending.insert(day, (lines, records));
+
+            while let Some(day) = expected {
+                let Some((lines, records)) = pending.remove(day) else {
+                    break;
+                };
+
+                on_day(*day, lines, records);
+                expected = remaining.next();
+            }
+        }
+    });
+}
+
+/// Records of a day whose solution could not be run at all.
+fn failed_records() -> Vec<Record> {
+    [Step::Part(1), Step::Part(2)]
+        .into_iter()
+        .map(|step| Record::without_result(step, Status::Crashed))
+        .collect()
 }
 
 #[allow(dead_code)]
@@ -61,43 +292,109 @@ impl From<std::io::Error> for Error {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    get_bin_path(day)
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the records they report.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, Limits, get_path_for_bin};
+    use crate::template::report::{Record, Status, Step};
+    use crate::template::{ANSI_BOLD, ANSI_RESET, Day, get_bin_name, runner};
     use std::{
         io::{BufRead, BufReader},
         path::Path,
         process::{Command, Stdio},
+        sync::{
+            Mutex,
+            atomic::{AtomicBool, Ordering},
+            mpsc::{self, RecvTimeoutError},
+        },
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// How often the deadline of the running step is checked.
+    const WATCHDOG_INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Start of the message printed by the default allocation error handler before aborting.
+    const ALLOC_FAILURE: &str = "memory allocation of";
+
+    /// A line of output of a child command.
+    pub enum Line {
+        Stdout(String),
+        Stderr(String),
+    }
+
+    impl Line {
+        pub fn print(&self) {
+            match self {
+                Line::Stdout(line) => println!("{line}"),
+                Line::Stderr(line) => eprintln!("{line}"),
+            }
+        }
+
+        /// Print the line right away, or hold it in `buffer` if there is one.
+        fn emit(self, buffer: Option<&Mutex<Vec<Line>>>) {
+            match buffer {
+                Some(buffer) => buffer.lock().unwrap().push(self),
+                None => self.print(),
+            }
+        }
+    }
+
+    /// Build every solution bin, without running them.
+    pub fn build_solutions(is_release: bool) -> Result<(), Error> {
+        let mut args = vec!["build", "--quiet", "--bins"];
+
+        if is_release {
+            args.push("--release");
+        }
+
+        Command::new("cargo").args(&args).status()?;
+        Ok(())
+    }
+
+    /// Run the solution bin for a given day.
+    /// Its output is forwarded to stdout/stderr, or held in `buffer` if there is one.
+    /// A step that exceeds the timeout is killed. A step that doesn't report a result is recorded as a failure.
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_memory: bool,
+        is_release: bool,
+        limits: Limits,
+        buffer: Option<&Mutex<Vec<Line>>>,
+    ) -> Result<Vec<Record>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let mut args = vec!["run".to_string(), "--quiet".into(), "--bin".into()];
+        args.push(get_bin_name(day));
 
         if is_release {
-            args.push("--release");
+            args.push("--release".into());
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+        args.push("--".into());
+        args.extend(
+            runner::Options {
+                // ask the child to report its results as machine-readable records.
+                json: true,
+                // mirror `--time` and `--memory` flags to child invocations.
+                time: is_timed,
+                memory: is_memory,
+                // the child applies the memory limit to itself, so that it doesn't apply to cargo.
+                memory_limit: limits.memory_limit,
+                ..runner::Options::default()
+            }
+            .to_args(),
+        );
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while grabbing records from stdout.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -108,85 +405,135 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        // NOTE: `cargo run` execs the solution, so killing the child kills the solution.
+        let cmd = Mutex::new(cmd);
+
+        // the step that is running, with the deadline of its first run if there is one.
+        let running: Mutex<Option<(Step, Option<Instant>)>> = Mutex::new(None);
+        let timed_out = AtomicBool::new(false);
+        let out_of_memory = AtomicBool::new(false);
+
+        let mut records = vec![];
+
+        thread::scope(|scope| {
+            let (done_tx, done_rx) = mpsc::channel::<()>();
+
+            scope.spawn(|| {
+                stderr.lines().for_each(|line| {
+                    let line = line.unwrap();
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+                    if line.contains(ALLOC_FAILURE) {
+                        out_of_memory.store(true, Ordering::Relaxed);
+                    }
+
+                    Line::Stderr(line).emit(buffer);
+                });
             });
+
+            if limits.timeout.is_some() {
+                let (running, timed_out, cmd) = (&running, &timed_out, &cmd);
+
+                scope.spawn(move || {
+                    while let Err(RecvTimeoutError::Timeout) =
+                        done_rx.recv_timeout(WATCHDOG_INTERVAL)
+                    {
+                        let is_expired = matches!(
+                            *running.lock().unwrap(),
+                            Some((_, Some(deadline))) if Instant::now() >= deadline
+                        );
+
+                        if is_expired {
+                            timed_out.store(true, Ordering::Relaxed);
+                            let _ = cmd.lock().unwrap().kill();
+                            break;
+                        }
+                    }
+                });
+            }
+
+            for line in stdout.lines() {
+                let line = line.unwrap();
+
+                match Record::from_line(&line) {
+                    Some(Ok(record)) => {
+                        *running.lock().unwrap() = match record.status {
+                            Status::Running => Some((
+                                record.step,
+                                limits.timeout.map(|timeout| Instant::now() + timeout),
+                            )),
+                            Status::Benching => Some((record.step, None)),
+                            _ => None,
+                        };
+
+                        if !record.status.is_transient() {
+                            records.push(record);
+                        }
+                    }
+                    Some(Err(e)) => eprintln!("Could not parse record from line: {line} ({e})"),
+                    None => Line::Stdout(line).emit(buffer),
+                }
+            }
+
+            // NOTE: stop the watchdog, the child is done.
+            drop(done_tx);
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        cmd.into_inner().unwrap().wait()?;
+
+        // the child died in the middle of a step, figure out why.
+        if let Some((step, _)) = running.into_inner().unwrap() {
+            let status = if timed_out.into_inner() {
+                Status::TimedOut
+            } else if out_of_memory.into_inner() {
+                Status::OutOfMemory
+            } else {
+                Status::Crashed
+            };
 
-        thread.join().unwrap();
-        cmd.wait()?;
+            Line::Stdout(format!("{step}: ✖ {ANSI_BOLD}{status}{ANSI_RESET}")).emit(buffer);
+            records.push(Record::without_result(step, status));
+        }
 
-        Ok(output)
+        Ok(records)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+    pub fn timing_from_records(records: &[Record], day: Day) -> super::Timing {
+        let mut timings = super::Timing::new(day);
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        for record in records.iter().filter(|r| r.status.is_failure()) {
+            timings.failures.push((record.step, record.status));
+        }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        for record in records {
+            if let Some(allocations) = record.allocations {
+                timings.allocations.push((record.step, allocations));
+            }
+        }
 
-                timings.total_nanos += nanos;
-            });
+        for record in records.iter().filter(|r| r.status == Status::Solved) {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let timing_str = Some(format!("{:.1?}", Duration::from_nanos(record.nanos as u64)));
 
-        timings
-    }
+            match record.step {
+                Step::Parse => {
+                    timings.parse = timing_str;
+                    timings.parse_stats = record.stats;
+                }
+                Step::Part(1) => {
+                    timings.part_1 = timing_str;
+                    timings.part_1_stats = record.stats;
+                }
+                Step::Part(2) => {
+                    timings.part_2 = timing_str;
+                    timings.part_2_stats = record.stats;
+                }
+                Step::Part(_) => continue,
+            }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+            timings.total_nanos += record.nanos;
+        }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        timings
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,47 +552,58 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::time::Duration;
+
+        use super::timing_from_records;
 
         use crate::day;
+        use crate::template::report::{Record, Status, Step};
+        use crate::template::stats::Stats;
+
+        fn record(step: Step, nanos: f64, status: Status) -> Record {
+            Record {
+                answer: (status == Status::Solved).then(|| "42".into()),
+                nanos,
+                samples: 100,
+                ..Record::without_result(step, status)
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_execution_times() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    record(Step::Part(1), 74.13, Status::Solved),
+                    record(Step::Part(2), 74_130_000.0, Status::Solved),
                 ],
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_parse_step() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    record(Step::Parse, 1000.0, Status::Solved),
+                    record(Step::Part(1), 2000.0, Status::Solved),
+                    record(Step::Part(2), 3000.0, Status::Solved),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos, 6000_f64);
+            assert_eq!(res.parse.unwrap(), "1.0µs");
+            assert_eq!(res.part_1.unwrap(), "2.0µs");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn collects_missing_parts() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    record(Step::Part(1), 10.0, Status::Unsolved),
+                    record(Step::Part(2), 10.0, Status::Unsolved),
                 ],
                 day!(1),
             );
@@ -253,5 +611,111 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn collects_statistics() {
+            let stats = Stats::from_samples(&[Duration::from_nanos(74); 100]);
+            let res = timing_from_records(
+                &[
+                    Record {
+                        stats,
+                        ..record(Step::Part(1), 74.0, Status::Solved)
+                    },
+                    record(Step::Part(2), 1_000_000.0, Status::Solved),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1_stats, stats);
+            assert_eq!(res.part_2_stats.is_none(), true);
+        }
+
+        #[test]
+        fn collects_failures() {
+            let res = timing_from_records(
+                &[
+                    record(Step::Part(1), 1000.0, Status::Solved),
+                    Record::without_result(Step::Part(2), Status::TimedOut),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 1000_f64);
+            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.failures, vec![(Step::Part(2), Status::TimedOut)]);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Summary;
+    use crate::{
+        day,
+        template::{
+            answers::Check,
+            report::{Record, Status, Step},
+        },
+    };
+
+    fn record(step: Step, check: Option<Check>) -> Record {
+        Record {
+            answer: Some("42".into()),
+            samples: 1,
+            check,
+            ..Record::without_result(step, Status::Solved)
+        }
+    }
+
+    #[test]
+    fn tallies_verdicts() {
+        let mut summary = Summary::default();
+        summary.add(
+            day!(1),
+            &[
+                record(Step::Parse, None),
+                record(Step::Part(1), Some(Check::Correct)),
+                record(Step::Part(2), Some(Check::Unknown)),
+            ],
+        );
+        summary.add(
+            day!(2),
+            &[record(
+                Step::Part(2),
+                Some(Check::Wrong {
+                    expected: "41".into(),
+                }),
+            )],
+        );
+
+        assert_eq!(summary.correct, 1);
+        assert_eq!(summary.unknown, 1);
+        assert_eq!(summary.wrong, vec![(day!(2), 2)]);
+        assert_eq!(summary.is_success(), false);
+    }
+
+    #[test]
+    fn tallies_failures() {
+        let mut summary = Summary::default();
+        summary.add(
+            day!(1),
+            &[
+                record(Step::Part(1), Some(Check::Correct)),
+                Record::without_result(Step::Part(2), Status::OutOfMemory),
+            ],
+        );
+
+        assert_eq!(summary.correct, 1);
+        assert_eq!(summary.unknown, 0);
+        assert_eq!(
+            summary.failed,
+            vec![(day!(1), Step::Part(2), Status::OutOfMemory)]
+        );
+        assert_eq!(summary.is_success(), false);
+    }
+
+    #[test]
+    fn succeeds_without_wrong_answers() {
+        let mut summary = Summary::default();
+        summary.add(day!(1), &[record(Step::Part(1), Some(Check::Unknown))]);
+        assert_eq!(summary.is_success(), true);
     }
 }
@@ -1,56 +1,269 @@
 /// Encapsulates code that interacts with solution functions.
-use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, process};
+
+use clap::{Args, Parser};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answer::Answer;
+use crate::template::answers::{self, Check};
+use crate::template::aoc_client::{self, AocClient, AocClientError, Submission, Verdict};
+use crate::template::memory::{self, Allocations};
+use crate::template::report::{Record, Status, Step};
+use crate::template::stats::Stats;
+use crate::template::submissions;
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day};
+
+/// Prefix of the line that follows a benched part with its statistics.
+const STATS_PREFIX: &str = "  └ ";
+
+/// Options of the runner. Unless configured beforehand, they are read from the command-line arguments.
+/// These are the flags of the solution binaries, which `solve` and `run_multi` pass on with [`Options::to_args`].
+#[derive(Clone, Debug, Default, Args)]
+pub struct Options {
+    /// Bench every step.
+    #[arg(long)]
+    pub time: bool,
+    /// Emit a machine-readable record of every step.
+    #[arg(long, hide = true)]
+    pub json: bool,
+    /// Measure the heap usage of the first run of every step.
+    #[arg(long)]
+    pub memory: bool,
+    /// Cap on the address space of the process in MiB.
+    #[arg(long, value_name = "MiB")]
+    pub memory_limit: Option<u64>,
+    /// Submit the answer of a part to Advent of Code.
+    #[arg(long, value_name = "PART", value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub submit: Option<u8>,
+    /// Submit even if the submission ledger rules the answer out.
+    #[arg(long, requires = "submit")]
+    pub force: bool,
+}
+
+impl Options {
+    /// Returns the command-line arguments of these options, to pass them on to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        for (flag, is_set) in [
+            ("--time", self.time),
+            ("--json", self.json),
+            ("--memory", self.memory),
+            ("--force", self.force),
+        ] {
+            if is_set {
+                args.push(flag.to_string());
+            }
+        }
+
+        if let Some(memory_limit) = self.memory_limit {
+            args.extend(["--memory-limit".to_string(), memory_limit.to_string()]);
+        }
+
+        if let Some(part) = self.submit {
+            args.extend(["--submit".to_string(), part.to_string()]);
+        }
+
+        args
+    }
+}
+
+/// The command-line interface of the solution binaries.
+#[derive(Parser)]
+#[command(about = "Runs the solution of a day, usually through `cargo solve <day>`.")]
+struct SolutionCli {
+    #[command(flatten)]
+    options: Options,
+}
+
+static OPTIONS: OnceLock<Options> = OnceLock::new();
+
+/// Records of the steps run in-process, see [`collect`].
+static COLLECTED: Mutex<Option<Vec<Record>>> = Mutex::new(None);
+
+/// Configure the runner instead of reading its options from the command-line arguments.
+/// Has no effect if the runner has already been used.
+pub fn configure(options: Options) {
+    let _ = OPTIONS.set(options);
+}
+
+fn options() -> &'static Options {
+    OPTIONS.get_or_init(|| SolutionCli::parse().options)
+}
+
+/// Apply the memory limit of the options to the current process.
+/// Allocations past the limit fail, which aborts the solution with an "out of memory" error.
+/// This is only called from the `main` of solution binaries, as it affects the whole process.
+pub fn limit_memory() {
+    let Some(limit) = options().memory_limit else {
+        return;
+    };
+
+    #[cfg(target_os = "linux")]
+    {
+        let bytes = limit.saturating_mul(1024 * 1024);
+        let rlimit = libc::rlimit {
+            rlim_cur: bytes,
+            rlim_max: bytes,
+        };
+
+        // SAFETY: `rlimit` is a valid, initialized struct that outlives the call.
+        if unsafe { libc::setrlimit(libc::RLIMIT_AS, &raw const rlimit) } != 0 {
+            eprintln!(
+                "Failed to apply memory limit: {}",
+                std::io::Error::last_os_error()
+            );
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    eprintln!("Warning: `--memory-limit` of {limit}MiB is ignored, it is only supported on Linux.");
+}
+
+/// Run a solution in-process and return the records of the steps it ran.
+/// A panicking solution is contained, only the steps it completed are returned.
+pub(crate) fn collect(run: impl FnOnce()) -> Vec<Record> {
+    *COLLECTED.lock().unwrap() = Some(vec![]);
+    let _ = panic::catch_unwind(AssertUnwindSafe(run));
+    COLLECTED.lock().unwrap().take().unwrap_or_default()
+}
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Run a part of a solution. Its answer can be anything that converts to an [`Answer`], e.g. an integer or a grid.
+pub fn run_part<I: Copy, T: Into<Answer>>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, stats, allocations) = run_timed(
+        |input| func(input).map(Into::into),
+        input,
+        Step::Part(part),
+        |result| print_result(result, &part_str, "", ""),
+    );
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let answer = result.as_ref().map(ToString::to_string);
+    let check = answers::check(day, part, answer.as_deref());
+
+    let samples = stats.map_or(1, |stats| stats.samples);
+    print_result(
+        &result,
+        &part_str,
+        &format_duration(&duration, samples, allocations),
+        &format_check(&check, answer.as_deref()),
+    );
+
+    if let Some(stats) = stats {
+        print_stats(&stats);
+    }
+
+    report(Record {
+        step: Step::Part(part),
+        answer,
+        #[allow(clippy::cast_precision_loss)]
+        nanos: duration.as_nanos() as f64,
+        samples,
+        status: if result.is_some() {
+            Status::Solved
+        } else {
+            Status::Unsolved
+        },
+        stats,
+        check: Some(check),
+        allocations,
+    });
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(&result, day, part);
+    }
+}
+
+/// Run the shared parse step of a solution and return its output.
+/// It is timed the same way as a part, but reported on its own line.
+pub fn run_parse<I: Copy, T>(func: impl Fn(I) -> T, input: I) -> T {
+    let (result, duration, stats, allocations) =
+        run_timed(func, input, Step::Parse, |_| print!("Parse:"));
+
+    let samples = stats.map_or(1, |stats| stats.samples);
+    print!("\r");
+    println!("Parse:{}", format_duration(&duration, samples, allocations));
+
+    if let Some(stats) = stats {
+        print_stats(&stats);
     }
+
+    report(Record {
+        step: Step::Parse,
+        answer: None,
+        #[allow(clippy::cast_precision_loss)]
+        nanos: duration.as_nanos() as f64,
+        samples,
+        status: Status::Solved,
+        stats,
+        check: None,
+        allocations,
+    });
+
+    result
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// The progress of the step is reported, so that `run_multi` can enforce a timeout on its first run.
+/// With `--memory`, the heap usage of the first run is measured as well.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    step: Step,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+) -> (T, Duration, Option<Stats>, Option<Allocations>) {
+    report(Record::without_result(step, Status::Running));
 
-        func(input)
-    };
-    let base_time = timer.elapsed();
+    let run = || {
+        let timer = Instant::now();
+        let result = {
+            #[cfg(feature = "dhat-heap")]
+            let _profiler = dhat::Profiler::new_heap();
 
-    hook(&result);
+            func(input)
+        };
+        (result, timer.elapsed())
+    };
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let ((result, base_time), allocations) = if options().memory {
+        memory::measure(run)
     } else {
-        (base_time, 1)
+        (run(), None)
     };
 
-    (result, run.0, run.1)
+    // NOTE: report before the hook prints, records must start on a line of their own.
+    if options().time {
+        report(Record::without_result(step, Status::Benching));
+    }
+
+    hook(&result);
+
+    if options().time {
+        let stats = bench(func, input, &base_time);
+        (result, stats.median, Some(stats), allocations)
+    } else {
+        (result, base_time, None, allocations)
+    }
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Bench a solution part: the function is warmed up, then sampled and summarized.
+/// The median is reported as the representative duration since it is robust to hiccups.
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> Stats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
@@ -59,6 +272,11 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
+    // NOTE: warm up caches and branch predictors before sampling.
+    for _ in 0..(bench_iterations / 10).max(1) {
+        black_box(func(black_box(input)));
+    }
+
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
@@ -67,36 +285,59 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    // NOTE: at least ten samples are collected.
+    Stats::from_samples(&timers).unwrap()
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+fn format_duration(
+    duration: &Duration,
+    samples: usize,
+    allocations: Option<Allocations>,
+) -> String {
+    let allocations = allocations.map_or_else(String::new, |x| format!(" · {x}"));
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
     if samples == 1 {
-        format!(" ({duration:.1?})")
+        format!(" ({duration:.1?}{allocations})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(" ({duration:.1?} @ {samples} samples{allocations})")
+    }
+}
+
+fn format_check(check: &Check, answer: Option<&str>) -> String {
+    match check {
+        Check::Correct => " ✔".into(),
+        Check::Unknown => " ?".into(),
+        Check::Wrong { expected } => format!(
+            " ✘ (expected {ANSI_BOLD}{expected}{ANSI_RESET}, got {ANSI_BOLD}{}{ANSI_RESET})",
+            answer.unwrap_or("nothing")
+        ),
+    }
+}
+
+fn print_stats(stats: &Stats) {
+    println!("{STATS_PREFIX}{stats}");
+}
+
+/// Emit a machine-readable record of a step if the `--json` flag is set,
+/// or hand it over to [`collect`] if the solution runs in-process.
+fn report(record: Record) {
+    if let Some(records) = COLLECTED.lock().unwrap().as_mut() {
+        // NOTE: progress only matters to a parent process watching the solution.
+        if !record.status.is_transient() {
+            records.push(record);
+        }
+    } else if options().json {
+        println!("{}", record.to_line());
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(result: &Option<Answer>, part: &str, duration_str: &str, check_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
             if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+                let str = format!("{part}: ▼ {duration_str}{check_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -105,12 +346,18 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                     println!("{result}");
                 }
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                let str =
+                    format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}{check_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
                     print!("\r");
                     println!("{str}");
+
+                    // NOTE: show the drawing the letters were read from, to double-check them.
+                    if let Some(grid) = result.grid() {
+                        println!("{grid}");
+                    }
                 }
             }
         }
@@ -119,49 +366,108 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 print!("{part}: ✖");
             } else {
                 print!("\r");
-                println!("{part}: ✖             ");
+                println!("{part}: ✖{check_str}             ");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
+/// Try to submit one part of the solution if:
+///  1. it is the part passed to `--submit`.
+///  2. an Advent of Code session cookie is configured.
+///  3. the submission ledger doesn't rule the answer out, unless `--force` is passed.
+fn submit_result(
+    result: &Answer,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
+) -> Option<Result<Submission, AocClientError>> {
+    if options().submit != Some(part) {
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to set up the Advent of Code client: {e}");
+            process::exit(1);
+        }
+    };
+
+    let Some(answer) = result.decode() else {
+        eprintln!("Could not read the letters drawn by the answer, please submit it by hand.");
+        return None;
+    };
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let is_forced = options().force;
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let ledger = match submissions::read(day, part) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Not submitting {answer}: failed to read the submissions ledger: {e}");
+            return None;
+        }
     };
 
-    if part_submit != part {
+    if let Some(refusal) = ledger.check(&answer)
+        && !is_forced
+    {
+        eprintln!("Not submitting {answer}: {refusal}. Pass --force to submit it anyway.");
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+    // NOTE: cooldowns apply to all puzzles, so wait out the ones of other parts as well.
+    if let Some(lockout) = submissions::lockout() {
+        aoc_client::countdown(lockout, "Waiting for the cooldown of the last submission:");
+    }
+
+    println!("Submitting result to Advent of Code...");
+    let submission = client.submit_patiently(day, part, &answer, |submission, cooldown| {
+        println!("{}", submission.message);
+        record_submission(day, part, &answer, submission);
+        aoc_client::countdown(cooldown, "Resubmitting in");
+
+        // NOTE: the answer may have been judged by another run in the meantime.
+        match submissions::read(day, part).map(|ledger| ledger.check(&answer)) {
+            Ok(Some(refusal)) if !is_forced => {
+                eprintln!("Not resubmitting {answer}: {refusal}.");
+                false
+            }
+            Err(e) => {
+                eprintln!("Not resubmitting {answer}: failed to read the submissions ledger: {e}");
+                false
+            }
+            _ => true,
+        }
+    });
+
+    match &submission {
+        Ok(submission) => {
+            println!("{}", submission.message);
+            record_submission(day, part, &answer, submission);
+
+            if submission.verdict == Verdict::Correct {
+                match answers::store(day, part, &answer) {
+                    Ok(path) => println!("🎄 Recorded accepted answer to \"{}\".", path.display()),
+                    Err(e) => eprintln!("Failed to record accepted answer: {e}"),
+                }
+            }
+
+            // NOTE: part two is only revealed once part one is solved.
+            if submission.verdict == Verdict::Correct && part == 1 {
+                match client.save_puzzle(day) {
+                    Ok(_) => println!("🎄 Updated the puzzle description with part two."),
+                    Err(e) => eprintln!("Failed to update the puzzle description: {e}"),
+                }
+            }
+        }
+        Err(e) => eprintln!("Failed to submit answer: {e}"),
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    Some(submission)
+}
+
+fn record_submission(day: Day, part: u8, answer: &str, submission: &Submission) {
+    if let Err(e) = submissions::record(day, part, answer, submission) {
+        eprintln!("Failed to record submission: {e}");
+    }
 }
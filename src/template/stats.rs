@@ -0,0 +1,156 @@
+/// Summary statistics over a set of benchmark samples.
+/// Outliers are detected with Tukey's fences and excluded from every figure but the sample count.
+use std::{fmt::Display, time::Duration};
+
+/// Multiplier applied to the interquartile range to place Tukey's fences.
+const TUKEY_FENCE: f64 = 1.5;
+
+/// Two-sided z-score of a 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+/// Statistics computed from the samples of a single benchmark.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    /// Number of collected samples, including outliers.
+    pub samples: usize,
+    /// Number of samples that fell outside of Tukey's fences.
+    pub outliers: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    /// Half-width of the 95% confidence interval of the mean.
+    pub ci95: Duration,
+}
+
+impl Stats {
+    /// Computes statistics from a set of samples, returns [`None`] if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let mut sorted: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - TUKEY_FENCE * iqr, q3 + TUKEY_FENCE * iqr);
+
+        let kept: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (low..=high).contains(x))
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / n;
+
+        let std_dev = if kept.len() > 1 {
+            (kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            samples: samples.len(),
+            outliers: samples.len() - kept.len(),
+            min: from_nanos(kept[0]),
+            median: from_nanos(percentile(&kept, 0.5)),
+            mean: from_nanos(mean),
+            p95: from_nanos(percentile(&kept, 0.95)),
+            max: from_nanos(kept[kept.len() - 1]),
+            std_dev: from_nanos(std_dev),
+            ci95: from_nanos(Z_95 * std_dev / n.sqrt()),
+        })
+    }
+}
+
+/// Linearly interpolated percentile of an ascending, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = p * (sorted.len() - 1) as f64;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - rank.floor())
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.1?} · median {:.1?} · mean {:.1?} · p95 {:.1?} · max {:.1?} · σ {:.1?} · ci95 ±{:.1?} · outliers {}/{}",
+            self.min,
+            self.median,
+            self.mean,
+            self.p95,
+            self.max,
+            self.std_dev,
+            self.ci95,
+            self.outliers,
+            self.samples
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = Stats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn computes_order_statistics() {
+        let stats = Stats::from_samples(&nanos(&[50, 10, 40, 20, 30])).unwrap();
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.p95, Duration::from_nanos(48));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.std_dev, Duration::from_nanos(16));
+        assert_eq!(stats.ci95, Duration::from_nanos(14));
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 1000])).unwrap();
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(12));
+        assert_eq!(stats.mean, Duration::from_nanos(11));
+    }
+}
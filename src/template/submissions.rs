@@ -0,0 +1,375 @@
+/// Ledger of the answers submitted to Advent of Code, used to avoid resubmitting known wrong answers.
+/// Submissions live in `data/submissions`, one JSON file per day and part, e.g. `01-2.json`.
+/// The ledger also remembers the cooldowns of the website, which apply to all puzzles at once.
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::{Submission, Verdict};
+use crate::template::{Day, profile};
+
+/// A submitted answer and the verdict of the website.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub answer: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch until which the website asked not to submit another answer.
+    pub wait_until: Option<u64>,
+}
+
+/// The submissions of a part, oldest first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub attempts: Vec<Attempt>,
+}
+
+/// Why an answer should not be submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved, with the given answer.
+    Solved { answer: String },
+    /// The answer was rejected before.
+    Rejected { verdict: Verdict },
+    /// The answer is at least an answer the website found too high.
+    AboveBound { bound: i128 },
+    /// The answer is at most an answer the website found too low.
+    BelowBound { bound: i128 },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "the part was already solved with {answer}"),
+            Refusal::Rejected { verdict } => write!(f, "it was already rejected as {verdict}"),
+            Refusal::AboveBound { bound } => write!(f, "{bound} was already too high"),
+            Refusal::BelowBound { bound } => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+impl Ledger {
+    /// Returns why an answer should not be submitted, if it shouldn't.
+    pub fn check(&self, answer: &str) -> Option<Refusal> {
+        if let Some(attempt) = self.attempts.iter().find(|x| x.verdict == Verdict::Correct) {
+            return Some(Refusal::Solved {
+                answer: attempt.answer.clone(),
+            });
+        }
+
+        if let Some(attempt) = self
+            .attempts
+            .iter()
+            .find(|x| x.answer == answer && x.verdict.is_rejection())
+        {
+            return Some(Refusal::Rejected {
+                verdict: attempt.verdict,
+            });
+        }
+
+        // NOTE: bounds only apply to numeric answers.
+        let value: i128 = answer.parse().ok()?;
+
+        let bound = |verdict: Verdict| {
+            self.attempts
+                .iter()
+                .filter(move |x| x.verdict == verdict)
+                .filter_map(|x| x.answer.parse::<i128>().ok())
+        };
+
+        if let Some(bound) = bound(Verdict::TooHigh).min().filter(|&x| value >= x) {
+            return Some(Refusal::AboveBound { bound });
+        }
+
+        if let Some(bound) = bound(Verdict::TooLow).max().filter(|&x| value <= x) {
+            return Some(Refusal::BelowBound { bound });
+        }
+
+        None
+    }
+
+    /// Returns until when the last cooldown of the part lasts, if it had one.
+    pub fn wait_until(&self) -> Option<u64> {
+        self.attempts.iter().filter_map(|x| x.wait_until).max()
+    }
+}
+
+/// Returns the path to the ledger of a part.
+#[must_use]
+pub fn get_path(day: Day, part: u8) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join(profile::data_dir("submissions"))
+        .join(format!("{day}-{part}.json"))
+}
+
+/// Reads the ledger of a part, which is empty until an answer is submitted.
+pub fn read(day: Day, part: u8) -> Result<Ledger, String> {
+    read_from(&get_path(day, part))
+}
+
+/// Appends a submission to the ledger of a part, stamped with the current time.
+pub fn record(
+    day: Day,
+    part: u8,
+    answer: &str,
+    submission: &Submission,
+) -> Result<PathBuf, String> {
+    let path = get_path(day, part);
+    // NOTE: an unreadable ledger is not replaced, as it holds the answers to refuse.
+    let mut ledger = read_from(&path)?;
+    let timestamp = now();
+
+    ledger.attempts.push(Attempt {
+        answer: answer.to_string(),
+        timestamp,
+        verdict: submission.verdict,
+        wait_until: submission.cooldown.map(|x| timestamp + x.as_secs()),
+    });
+
+    store_to(&path, &ledger)?;
+    Ok(path)
+}
+
+/// Returns how much longer the last cooldown recorded for any part lasts, if it still does.
+pub fn lockout() -> Option<Duration> {
+    let dir = env::current_dir()
+        .unwrap()
+        .join(profile::data_dir("submissions"));
+
+    let ledgers = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| read_from(&entry.path()).ok());
+
+    lockout_at(ledgers, now())
+}
+
+fn lockout_at(ledgers: impl Iterator<Item = Ledger>, now: u64) -> Option<Duration> {
+    ledgers
+        .filter_map(|ledger| ledger.wait_until())
+        .max()
+        .filter(|&until| until > now)
+        .map(|until| Duration::from_secs(until - now))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs())
+}
+
+fn read_from(path: &Path) -> Result<Ledger, String> {
+    let ledger = match fs::read_to_string(path) {
+        Ok(json) => Ledger::try_from(json.as_str()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+        Err(e) => Err(e.to_string()),
+    };
+
+    ledger.map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn store_to(path: &Path, ledger: &Ledger) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|x| x.to_string())?;
+    }
+
+    let json = JsonValue::from(ledger)
+        .format()
+        .map_err(|x| x.to_string())?;
+
+    fs::write(path, json).map_err(|x| x.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Ledger> for JsonValue {
+    fn from(value: &Ledger) -> Self {
+        JsonValue::Array(
+            value
+                .attempts
+                .iter()
+                .map(|attempt| {
+                    let mut map = HashMap::from([
+                        ("answer".into(), JsonValue::String(attempt.answer.clone())),
+                        (
+                            "timestamp".into(),
+                            JsonValue::Number(attempt.timestamp as f64),
+                        ),
+                        (
+                            "verdict".into(),
+                            JsonValue::String(attempt.verdict.to_string()),
+                        ),
+                    ]);
+
+                    if let Some(wait_until) = attempt.wait_until {
+                        map.insert("wait_until".into(), JsonValue::Number(wait_until as f64));
+                    }
+
+                    JsonValue::Object(map)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl TryFrom<&str> for Ledger {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON file."))?;
+
+        let attempts: &Vec<JsonValue> =
+            json.get().ok_or("expected JSON document to be an array.")?;
+
+        let attempts = attempts
+            .iter()
+            .map(|attempt| {
+                let answer: &String = attempt["answer"]
+                    .get()
+                    .ok_or("expected answer to be a string.")?;
+                let timestamp: &f64 = attempt["timestamp"]
+                    .get()
+                    .ok_or("expected timestamp to be a number.")?;
+                let verdict: &String = attempt["verdict"]
+                    .get()
+                    .ok_or("expected verdict to be a string.")?;
+                let wait_until: Option<&f64> = match attempt {
+                    JsonValue::Object(map) => map.get("wait_until").and_then(|x| x.get()),
+                    _ => None,
+                };
+
+                Ok(Attempt {
+                    answer: answer.clone(),
+                    timestamp: *timestamp as u64,
+                    verdict: verdict.parse()?,
+                    wait_until: wait_until.map(|x| *x as u64),
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Ledger { attempts })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use std::time::Duration;
+
+    use super::{Attempt, Ledger, Refusal, lockout_at, read_from, store_to};
+    use crate::template::aoc_client::Verdict;
+
+    fn ledger(attempts: &[(&str, Verdict)]) -> Ledger {
+        Ledger {
+            attempts: attempts
+                .iter()
+                .map(|(answer, verdict)| Attempt {
+                    answer: answer.to_string(),
+                    timestamp: 1_700_000_000,
+                    verdict: *verdict,
+                    wait_until: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn allows_new_answers() {
+        assert_eq!(Ledger::default().check("42"), None);
+        assert_eq!(
+            ledger(&[("abc", Verdict::Wrong), ("50", Verdict::RateLimited)]).check("50"),
+            None
+        );
+    }
+
+    #[test]
+    fn refuses_rejected_answers() {
+        assert_eq!(
+            ledger(&[("abc", Verdict::Wrong)]).check("abc"),
+            Some(Refusal::Rejected {
+                verdict: Verdict::Wrong
+            })
+        );
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let ledger = ledger(&[
+            ("100", Verdict::TooHigh),
+            ("80", Verdict::TooHigh),
+            ("-5", Verdict::TooLow),
+        ]);
+
+        assert_eq!(ledger.check("90"), Some(Refusal::AboveBound { bound: 80 }));
+        assert_eq!(ledger.check("-7"), Some(Refusal::BelowBound { bound: -5 }));
+        assert_eq!(ledger.check("79"), None);
+        assert_eq!(ledger.check("HI"), None);
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        assert_eq!(
+            ledger(&[("3", Verdict::TooLow), ("7", Verdict::Correct)]).check("8"),
+            Some(Refusal::Solved { answer: "7".into() })
+        );
+    }
+
+    #[test]
+    fn computes_lockouts() {
+        let mut waited = ledger(&[("1", Verdict::Wrong)]);
+        waited.attempts[0].wait_until = Some(1_700_000_060);
+        let mut waiting = ledger(&[("2", Verdict::RateLimited)]);
+        waiting.attempts[0].wait_until = Some(1_700_000_090);
+
+        assert_eq!(
+            lockout_at([waited.clone(), waiting].into_iter(), 1_700_000_070),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(lockout_at([waited].into_iter(), 1_700_000_070), None);
+        assert_eq!(lockout_at([Ledger::default()].into_iter(), 0), None);
+    }
+
+    #[test]
+    fn roundtrips_ledgers() {
+        let dir = env::temp_dir().join(format!("aoc-submissions-{}", std::process::id()));
+        let path = dir.join("01-1.json");
+        let mut ledger = ledger(&[
+            ("100", Verdict::TooHigh),
+            ("60", Verdict::RateLimited),
+            ("\"quoted\"", Verdict::Correct),
+        ]);
+        ledger.attempts[1].wait_until = Some(1_700_000_030);
+
+        store_to(&path, &ledger).unwrap();
+        assert_eq!(read_from(&path), Ok(ledger));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fails_on_unreadable_ledgers() {
+        let dir = env::temp_dir().join(format!("aoc-submissions-bad-{}", std::process::id()));
+        let path = dir.join("01-1.json");
+
+        assert_eq!(read_from(&path), Ok(Ledger::default()));
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "[{\"answer\": 1}").unwrap();
+        assert!(read_from(&path).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
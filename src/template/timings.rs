@@ -1,19 +1,99 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap, fmt::Display, fs, io::Error, path::PathBuf, str::FromStr, time::Duration,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::memory::Allocations;
+use crate::template::report::{Status, Step};
+use crate::template::stats::Stats;
+use crate::template::{Day, profile};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Returns the path of the timings of the current year, e.g. `data/timings.json` or `data/2024/timings.json`.
+fn get_path() -> PathBuf {
+    profile::shared_dir().join("timings.json")
+}
+
+/// Parses a duration formatted like [`Duration`]'s debug output, e.g. `74.0ns` or `1.2s`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let unit_start = s.find(|c: char| c.is_alphabetic())?;
+    let (value, unit) = s.split_at(unit_start);
+    let value: f64 = value.trim().parse().ok()?;
+
+    let nanos = match unit {
+        "ns" => 1.0,
+        "µs" | "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+
+    Duration::try_from_secs_f64(value * nanos / 1e9).ok()
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<Stats>,
+    pub part_2_stats: Option<Stats>,
+    pub parse_stats: Option<Stats>,
+    /// Steps that were interrupted, e.g. because they timed out.
+    pub failures: Vec<(Step, Status)>,
+    /// Heap usage of the steps that were measured with `--memory`.
+    pub allocations: Vec<(Step, Allocations)>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// Creates the timing of a day without any measurement.
+    pub fn new(day: Day) -> Self {
+        Timing {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_stats: None,
+            failures: vec![],
+            allocations: vec![],
+            total_nanos: 0.0,
+        }
+    }
+
+    /// Returns how a step failed, if it did.
+    pub fn failure(&self, step: Step) -> Option<Status> {
+        self.failures
+            .iter()
+            .find(|(failed, _)| *failed == step)
+            .map(|(_, status)| *status)
+    }
+
+    /// Returns the median duration of a step, or else its recorded duration, e.g. `74.1ms`.
+    pub fn duration(&self, step: Step) -> Option<Duration> {
+        let (stats, recorded) = match step {
+            Step::Parse => (self.parse_stats, &self.parse),
+            Step::Part(1) => (self.part_1_stats, &self.part_1),
+            Step::Part(_) => (self.part_2_stats, &self.part_2),
+        };
+
+        stats
+            .map(|stats| stats.median)
+            .or_else(|| recorded.as_deref().and_then(parse_duration))
+    }
+
+    /// Returns the highest peak heap usage of all steps, if any was measured.
+    pub fn peak_bytes(&self) -> Option<usize> {
+        self.allocations
+            .iter()
+            .map(|(_, allocations)| allocations.peak_bytes)
+            .max()
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -25,13 +105,17 @@ impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let path = get_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::File::create(path)?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+        fs::read_to_string(get_path())
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +135,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -65,6 +149,88 @@ impl Timings {
             .iter()
             .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
     }
+
+    /// Compare the median duration of every step in `new` with the same step in `self`.
+    /// Timings stored before statistics were kept are compared by their recorded durations instead,
+    /// or by their total if no step can be compared.
+    pub fn compare(&self, new: &Self) -> Vec<Change> {
+        let mut changes = vec![];
+
+        for timing in &new.data {
+            let Some(stored) = self.data.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+
+            let count = changes.len();
+
+            for (step, name) in [
+                (Step::Parse, "Parse"),
+                (Step::Part(1), "Part 1"),
+                (Step::Part(2), "Part 2"),
+            ] {
+                if let (Some(before), Some(after)) = (stored.duration(step), timing.duration(step))
+                {
+                    changes.push(Change {
+                        day: timing.day,
+                        step: name,
+                        before,
+                        after,
+                    });
+                }
+            }
+
+            if changes.len() == count && stored.total_nanos > 0.0 && timing.total_nanos > 0.0 {
+                changes.push(Change {
+                    day: timing.day,
+                    step: "Total",
+                    before: Duration::from_nanos(stored.total_nanos as u64),
+                    after: Duration::from_nanos(timing.total_nanos as u64),
+                });
+            }
+        }
+
+        changes
+    }
+}
+
+/// The change of the median duration of a step between two benchmarks.
+#[derive(Clone, Debug)]
+pub struct Change {
+    pub day: Day,
+    pub step: &'static str,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Change {
+    /// Relative change in percent, positive if the step got slower.
+    pub fn percent(&self) -> f64 {
+        let before = self.before.as_secs_f64();
+
+        if before == 0.0 {
+            return 0.0;
+        }
+
+        (self.after.as_secs_f64() - before) / before * 100.0
+    }
+
+    /// Whether the step got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let percent = self.percent();
+        let direction = if percent > 0.0 { "slower" } else { "faster" };
+
+        write!(
+            f,
+            "Day {} {}: {:.1?} → {:.1?} ({percent:+.0}% {direction})",
+            self.day, self.step, self.before, self.after
+        )
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -114,6 +280,14 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
 
@@ -133,6 +307,54 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        for (key, stats) in [
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+            ("parse_stats", &value.parse_stats),
+        ] {
+            map.insert(
+                key.into(),
+                stats.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
+
+        map.insert(
+            "failures".into(),
+            JsonValue::Array(
+                value
+                    .failures
+                    .iter()
+                    .map(|(step, status)| {
+                        JsonValue::Object(HashMap::from([
+                            ("part".into(), JsonValue::from(*step)),
+                            ("status".into(), JsonValue::String(status.to_string())),
+                        ]))
+                    })
+                    .collect(),
+            ),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "allocations".into(),
+            JsonValue::Array(
+                value
+                    .allocations
+                    .iter()
+                    .map(|(step, allocations)| {
+                        JsonValue::Object(HashMap::from([
+                            ("part".into(), JsonValue::from(*step)),
+                            (
+                                "peak_bytes".into(),
+                                JsonValue::Number(allocations.peak_bytes as f64),
+                            ),
+                            ("count".into(), JsonValue::Number(allocations.count as f64)),
+                        ]))
+                    })
+                    .collect(),
+            ),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -161,20 +383,185 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // NOTE: the parse step is optional, as are the statistics below.
+        let parse = match json.get("parse") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .cloned()
+                    .ok_or("Expected timing.parse to be null or string.")?,
+            ),
+        };
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: statistics are optional to keep reading timings stored by earlier versions.
+        let part_1_stats = read_stats(json, "part_1_stats")?;
+        let part_2_stats = read_stats(json, "part_2_stats")?;
+        let parse_stats = read_stats(json, "parse_stats")?;
+        let failures = read_failures(json)?;
+        let allocations = read_allocations(json)?;
+
         Ok(Timing {
             day,
+            parse,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats,
+            part_2_stats,
+            parse_stats,
+            failures,
+            allocations,
             total_nanos,
         })
     }
 }
 
+fn read_stats(json: &HashMap<String, JsonValue>, key: &str) -> Result<Option<Stats>, String> {
+    match json.get(key) {
+        None => Ok(None),
+        Some(v) if v.is_null() => Ok(None),
+        Some(v) => Stats::try_from(v).map(Some),
+    }
+}
+
+fn read_failures(json: &HashMap<String, JsonValue>) -> Result<Vec<(Step, Status)>, String> {
+    let Some(failures) = json.get("failures") else {
+        return Ok(vec![]);
+    };
+
+    failures
+        .get::<Vec<JsonValue>>()
+        .ok_or("Expected timing.failures to be an array.")?
+        .iter()
+        .map(|failure| {
+            let failure = failure
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected timing.failures to hold objects.")?;
+
+            let step = failure
+                .get("part")
+                .ok_or_else(|| "Expected failure.part to be a number or `parse`.".to_string())
+                .and_then(Step::try_from)?;
+
+            let status = failure
+                .get("status")
+                .and_then(|v| v.get::<String>())
+                .ok_or("Expected failure.status to be a string.")?
+                .parse()?;
+
+            Ok((step, status))
+        })
+        .collect()
+}
+
+fn read_allocations(json: &HashMap<String, JsonValue>) -> Result<Vec<(Step, Allocations)>, String> {
+    let Some(allocations) = json.get("allocations") else {
+        return Ok(vec![]);
+    };
+
+    allocations
+        .get::<Vec<JsonValue>>()
+        .ok_or("Expected timing.allocations to be an array.")?
+        .iter()
+        .map(|allocations| {
+            let allocations = allocations
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected timing.allocations to hold objects.")?;
+
+            let step = allocations
+                .get("part")
+                .ok_or_else(|| "Expected allocations.part to be a number or `parse`.".to_string())
+                .and_then(Step::try_from)?;
+
+            let [peak_bytes, count] = ["peak_bytes", "count"].map(|key| {
+                allocations
+                    .get(key)
+                    .and_then(|v| v.get::<f64>().copied())
+                    .ok_or(format!("Expected allocations.{key} to be a number."))
+            });
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            Ok((
+                step,
+                Allocations {
+                    peak_bytes: peak_bytes? as usize,
+                    count: count? as usize,
+                },
+            ))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+const STATS_DURATIONS: [&str; 7] = ["min", "median", "mean", "p95", "max", "std_dev", "ci95"];
+
+impl From<&Stats> for JsonValue {
+    fn from(value: &Stats) -> Self {
+        let durations = [
+            value.min,
+            value.median,
+            value.mean,
+            value.p95,
+            value.max,
+            value.std_dev,
+            value.ci95,
+        ];
+
+        #[allow(clippy::cast_precision_loss)]
+        let mut map: HashMap<String, JsonValue> = STATS_DURATIONS
+            .iter()
+            .zip(durations)
+            .map(|(key, duration)| ((*key).into(), JsonValue::Number(duration.as_nanos() as f64)))
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+            map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Stats {
+            samples: number("samples")? as usize,
+            outliers: number("outliers")? as usize,
+            min: duration("min")?,
+            median: duration("median")?,
+            mean: duration("mean")?,
+            p95: duration("p95")?,
+            max: duration("max")?,
+            std_dev: duration("std_dev")?,
+            ci95: duration("ci95")?,
+        })
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
@@ -187,22 +574,21 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
-                    part_2: None,
                     total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -223,6 +609,17 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "samples": 10, "outliers": 1, "min": 900000, "median": 1000000, "mean": 1000000, "p95": 1100000, "max": 1200000, "std_dev": 50000, "ci95": 30000 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let stats = timings.data[0].part_1_stats.unwrap();
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.outliers, 1);
+            assert_eq!(stats.median.as_nanos(), 1_000_000);
+            assert_eq!(timings.data[0].part_2_stats, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,7 +644,13 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
-        use std::collections::HashMap;
+        use crate::template::{
+            memory::Allocations,
+            report::{Status, Step},
+            stats::Stats,
+            timings::Timings,
+        };
+        use std::{collections::HashMap, time::Duration};
         use tinyjson::JsonValue;
 
         #[test]
@@ -266,6 +669,58 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn serializes_stats() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1_stats = Some(Stats {
+                samples: 10,
+                outliers: 2,
+                min: Duration::from_nanos(1),
+                median: Duration::from_nanos(2),
+                mean: Duration::from_nanos(3),
+                p95: Duration::from_nanos(4),
+                max: Duration::from_nanos(5),
+                std_dev: Duration::from_nanos(6),
+                ci95: Duration::from_nanos(7),
+            });
+            let value = JsonValue::from(timings.clone());
+            let parsed = Timings::try_from(value.stringify().unwrap()).unwrap();
+            assert_eq!(parsed.data[0].part_1_stats, timings.data[0].part_1_stats);
+            assert_eq!(parsed.data[0].part_2_stats, None);
+        }
+
+        #[test]
+        fn serializes_failures() {
+            let mut timings = get_mock_timings();
+            timings.data[0].failures = vec![(Step::Part(2), Status::TimedOut)];
+            let value = JsonValue::from(timings);
+            let parsed = Timings::try_from(value.stringify().unwrap()).unwrap();
+            assert_eq!(
+                parsed.data[0].failure(Step::Part(2)),
+                Some(Status::TimedOut)
+            );
+            assert_eq!(parsed.data[0].failure(Step::Part(1)), None);
+            assert_eq!(parsed.data[1].failures.is_empty(), true);
+        }
+
+        #[test]
+        fn serializes_allocations() {
+            let mut timings = get_mock_timings();
+            let allocations = Allocations {
+                peak_bytes: 2048,
+                count: 3,
+            };
+            timings.data[0].allocations = vec![(Step::Part(1), allocations)];
+            let value = JsonValue::from(timings);
+            let parsed = Timings::try_from(value.stringify().unwrap()).unwrap();
+            assert_eq!(
+                parsed.data[0].allocations,
+                vec![(Step::Part(1), allocations)]
+            );
+            assert_eq!(parsed.data[0].peak_bytes(), Some(2048));
+            assert_eq!(parsed.data[1].peak_bytes(), None);
+        }
     }
 
     mod is_day_complete {
@@ -278,42 +733,36 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
-                    part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![Timing::new(day!(1))],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -329,12 +778,7 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(3))],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,12 +793,7 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(2))],
             };
             let merged = timings.merge(&other);
 
@@ -381,4 +820,105 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+    mod compare {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                stats::Stats,
+                timings::{Timing, Timings, parse_duration},
+            },
+        };
+
+        fn timing(day: u8, part_1: Option<u64>, part_2: Option<u64>) -> Timing {
+            let stats = |nanos: Option<u64>| {
+                nanos.and_then(|x| Stats::from_samples(&[Duration::from_nanos(x); 10]))
+            };
+
+            Timing {
+                part_1: part_1.map(|x| format!("{x}ns")),
+                part_2: part_2.map(|x| format!("{x}ns")),
+                part_1_stats: stats(part_1),
+                part_2_stats: stats(part_2),
+                ..Timing::new(crate::template::Day::new(day).unwrap())
+            }
+        }
+
+        #[test]
+        fn compares_overlapping_parts() {
+            let stored = Timings {
+                data: vec![timing(1, Some(100), Some(200)), timing(2, Some(100), None)],
+            };
+            let new = Timings {
+                data: vec![timing(1, Some(135), Some(100)), timing(3, Some(1), Some(1))],
+            };
+
+            let changes = stored.compare(&new);
+            assert_eq!(changes.len(), 2);
+            assert_eq!(changes[0].day, day!(1));
+            assert_eq!(changes[0].step, "Part 1");
+            assert_eq!(changes[0].percent().round(), 35.0);
+            assert_eq!(changes[1].percent().round(), -50.0);
+        }
+
+        #[test]
+        fn detects_regressions() {
+            let stored = Timings {
+                data: vec![timing(1, Some(100), Some(100))],
+            };
+            let new = Timings {
+                data: vec![timing(1, Some(135), Some(105))],
+            };
+
+            let changes = stored.compare(&new);
+            assert_eq!(changes[0].is_regression(10.0), true);
+            assert_eq!(changes[1].is_regression(10.0), false);
+            assert_eq!(
+                changes[0].to_string(),
+                "Day 01 Part 1: 100.0ns → 135.0ns (+35% slower)"
+            );
+            assert_eq!(
+                changes[1].to_string(),
+                "Day 01 Part 2: 100.0ns → 105.0ns (+5% slower)"
+            );
+        }
+
+        #[test]
+        fn compares_timings_without_stats() {
+            let mut stored = Timings {
+                data: vec![timing(1, Some(100), Some(100)), timing(2, None, None)],
+            };
+            stored.data[0].part_1_stats = None;
+            stored.data[0].part_2_stats = None;
+            stored.data[0].part_2 = Some("1.5µs".into());
+            stored.data[1].total_nanos = 1000.0;
+
+            let mut new = Timings {
+                data: vec![timing(1, Some(135), Some(1200)), timing(2, None, None)],
+            };
+            new.data[1].total_nanos = 1500.0;
+
+            let changes = stored.compare(&new);
+            assert_eq!(changes.len(), 3);
+            assert_eq!(changes[0].step, "Part 1");
+            assert_eq!(changes[0].percent().round(), 35.0);
+            assert_eq!(changes[1].before, Duration::from_nanos(1500));
+            assert_eq!(changes[1].percent().round(), -20.0);
+            assert_eq!(changes[2].step, "Total");
+            assert_eq!(changes[2].percent().round(), 50.0);
+        }
+
+        #[test]
+        fn parses_recorded_durations() {
+            assert_eq!(parse_duration("74.0ns"), Some(Duration::from_nanos(74)));
+            assert_eq!(parse_duration("2.5µs"), Some(Duration::from_nanos(2500)));
+            assert_eq!(
+                parse_duration("74.1ms"),
+                Some(Duration::from_micros(74_100))
+            );
+            assert_eq!(parse_duration("1s"), Some(Duration::from_secs(1)));
+            assert_eq!(parse_duration("fast"), None);
+        }
+    }
 }
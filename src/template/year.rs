@@ -0,0 +1,296 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::template::{AllDays, Day, config};
+
+/// A year of Advent of Code (i.e. 2015 or later).
+///
+/// Up to 2024, an event lasts 25 days. From 2025 on, it lasts 12 days.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// assert_eq!(Year::new(2024).unwrap().days(), 25);
+/// assert_eq!(Year::new(2025).unwrap().days(), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+/// The first year of Advent of Code.
+const FIRST_YEAR: u16 = 2015;
+
+/// The first year whose event lasts 12 days instead of 25.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// The year selected for this process, see [`Year::select`].
+static SELECTED: OnceLock<Year> = OnceLock::new();
+
+impl Year {
+    /// The configured year when the crate was built, for const contexts such as [`day!`](crate::day).
+    /// The build script reads it from `aoc.toml`, unless `AOC_YEAR` is set.
+    pub const CONFIGURED: Option<Self> = match option_env!("AOC_YEAR") {
+        Some(year) => parse_const(year),
+        None => None,
+    };
+
+    /// Creates a [`Year`] from the provided value if Advent of Code took place in it,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Returns the default year of the repository, from `aoc.toml` or `AOC_YEAR`.
+    /// Overriding it relabels the files without a year, see [`Year::is_default`].
+    pub fn from_config() -> Option<Self> {
+        config::get().year
+    }
+
+    /// Selects the year of the puzzles for this process, e.g. with `--year`, instead of the default year.
+    /// Only the first selection counts.
+    pub fn select(self) {
+        let _ = SELECTED.set(self);
+    }
+
+    /// Returns the selected year, or the default year if none was selected.
+    pub fn current() -> Option<Self> {
+        SELECTED.get().copied().or_else(Self::from_config)
+    }
+
+    /// Whether this is the default year of the repository, whose files are laid out without a year,
+    /// e.g. `src/bin/01.rs` and `data/inputs/01.txt`. Files of other years are laid out as
+    /// `src/bin/2024_01.rs` and `data/2024/inputs/01.txt`.
+    ///
+    /// The files without a year belong to whichever year is the default, so setting `AOC_YEAR` to
+    /// another year than the one of `aoc.toml` makes them solutions of that year. Use [`Year::select`]
+    /// to work on another year next to the default one.
+    pub fn is_default(self) -> bool {
+        Self::from_config().is_none_or(|year| year == self)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub const fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the number of days of the event of this year.
+    pub const fn days(self) -> u8 {
+        if self.0 < FIRST_SHORT_YEAR { 25 } else { 12 }
+    }
+
+    /// Returns an iterator over every day of the event of this year.
+    pub fn all_days(self) -> AllDays {
+        AllDays::until(self.days())
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year from {FIRST_YEAR} on")
+    }
+}
+
+/// Parses a year in a const context, where [`FromStr`] is not available.
+const fn parse_const(s: &str) -> Option<Year> {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() || bytes.len() > 4 {
+        return None;
+    }
+
+    let mut year = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    Year::new(year)
+}
+
+/// Solutions live in `src/bin`, where cargo finds binaries without declaring them.
+const BIN_DIR: &str = "./src/bin";
+
+/// Returns the name of the binary of the solution of a day of the current year, e.g. `01` or `2024_01`.
+#[must_use]
+pub fn get_bin_name(day: Day) -> String {
+    PuzzleId::current(day).map_or_else(|| day.to_string(), PuzzleId::bin_name)
+}
+
+/// Returns the path of the solution of a day of the current year, e.g. `./src/bin/01.rs`.
+#[must_use]
+pub fn get_bin_path(day: Day) -> String {
+    format!("{BIN_DIR}/{}.rs", get_bin_name(day))
+}
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a year from 2015 on")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A puzzle of Advent of Code, i.e. a day of the event of a year.
+///
+/// # Display
+/// This value displays as the year and the two digit day, e.g. `2025/08`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    year: Year,
+    day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if the event of the year has the day, returns [`None`] otherwise.
+    pub const fn new(year: Year, day: Day) -> Option<Self> {
+        if day.into_inner() > year.days() {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+
+    /// Returns the puzzle of a day of the current year, see [`Year::current`].
+    pub fn current(day: Day) -> Option<Self> {
+        Self::new(Year::current()?, day)
+    }
+
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    pub const fn day(self) -> Day {
+        self.day
+    }
+
+    /// Returns the name of the binary of its solution, e.g. `01` for the default year or `2024_01`.
+    #[must_use]
+    pub fn bin_name(self) -> String {
+        if self.year.is_default() {
+            self.day.to_string()
+        } else {
+            format!("{}_{}", self.year, self.day)
+        }
+    }
+
+    /// Returns the path of its solution, e.g. `./src/bin/01.rs` or `./src/bin/2024_01.rs`.
+    #[must_use]
+    pub fn bin_path(self) -> String {
+        format!("{BIN_DIR}/{}.rs", self.bin_name())
+    }
+
+    /// Returns the Unix timestamp at which the puzzle unlocks.
+    pub fn unlock_time(self) -> i64 {
+        self.day.unlock_time(self.year.into_inner())
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('/').ok_or(PuzzleIdFromStrError)?;
+        let year: Year = year.parse().map_err(|_| PuzzleIdFromStrError)?;
+        let day = day
+            .parse()
+            .ok()
+            .and_then(Day::new)
+            .ok_or(PuzzleIdFromStrError)?;
+        Self::new(year, day).ok_or(PuzzleIdFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year and a day of its event, e.g. `2025/8`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleId, Year, parse_const};
+    use crate::template::Day;
+
+    #[test]
+    fn validates_years() {
+        assert_eq!(Year::new(2014), None);
+        assert_eq!("2015".parse::<Year>().ok(), Year::new(2015));
+        assert!("20x5".parse::<Year>().is_err());
+        assert_eq!(parse_const("2025"), Year::new(2025));
+        assert_eq!(parse_const("2025 "), None);
+    }
+
+    #[test]
+    fn validates_days_per_year() {
+        let day = |day| Day::new(day).unwrap();
+        let year = |year| Year::new(year).unwrap();
+
+        assert!(PuzzleId::new(year(2024), day(25)).is_some());
+        assert!(PuzzleId::new(year(2025), day(12)).is_some());
+        assert!(PuzzleId::new(year(2025), day(13)).is_none());
+        assert_eq!(year(2024).all_days().count(), 25);
+        assert_eq!(year(2025).all_days().last(), Some(day(12)));
+    }
+
+    #[test]
+    fn parses_puzzle_ids() {
+        let id: PuzzleId = "2025/8".parse().unwrap();
+        assert_eq!(id.to_string(), "2025/08");
+        assert_eq!(id.unlock_time(), 1_765_170_000);
+        assert!("2025/13".parse::<PuzzleId>().is_err());
+        assert!("2024/25".parse::<PuzzleId>().is_ok());
+        assert!("8".parse::<PuzzleId>().is_err());
+    }
+
+    #[test]
+    fn names_solutions_of_other_years() {
+        let id: PuzzleId = "2016/3".parse().unwrap();
+        assert_eq!(id.bin_name(), "2016_03");
+        assert_eq!(id.bin_path(), "./src/bin/2016_03.rs");
+    }
+}